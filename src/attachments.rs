@@ -0,0 +1,154 @@
+//! Types used with the attachment methods of the [`Client`](../struct.Client.html).
+
+use crate::response::Lookup;
+use serde::Deserialize;
+use std::io::{self, Read};
+
+/// A file or link attached to a record.
+#[derive(Debug, Deserialize)]
+pub struct Attachment {
+    pub id: String,
+
+    #[serde(rename = "File_Name")]
+    pub file_name: Option<String>,
+
+    /// Size of the file in bytes.
+    #[serde(rename = "Size")]
+    pub size: Option<String>,
+
+    /// The record the file is attached to.
+    #[serde(rename = "Parent_Id")]
+    pub parent_id: Option<Lookup>,
+
+    /// API name of the module of the record the file is attached to.
+    #[serde(rename = "$se_module")]
+    pub se_module: Option<String>,
+
+    /// Either `Attachment` for uploaded files, or `Link URL` for attached links.
+    #[serde(rename = "$type")]
+    pub attachment_type: Option<String>,
+
+    /// URL of attached links.
+    #[serde(rename = "$link_url")]
+    pub link_url: Option<String>,
+
+    #[serde(rename = "Owner")]
+    pub owner: Option<Lookup>,
+
+    #[serde(rename = "Created_By")]
+    pub created_by: Option<Lookup>,
+
+    #[serde(rename = "Modified_By")]
+    pub modified_by: Option<Lookup>,
+
+    #[serde(rename = "Created_Time")]
+    pub created_time: Option<String>,
+
+    #[serde(rename = "Modified_Time")]
+    pub modified_time: Option<String>,
+}
+
+/// The image of a record, returned by the [`get_photo()`](../struct.Client.html#method.get_photo)
+/// method.
+#[derive(Debug)]
+pub struct Photo {
+    /// MIME type of the image, such as `image/png`.
+    pub content_type: Option<String>,
+    pub data: Vec<u8>,
+}
+
+/// A file being downloaded from Zoho.
+///
+/// The file is streamed as it is read, so it is never held in memory all at once.
+///
+/// ### Example
+///
+/// ```no_run
+/// # use zoho_crm::Client;
+/// # let client_id = String::from("");
+/// # let client_secret = String::from("");
+/// # let refresh_token = String::from("");
+/// # let mut client = Client::with_creds(None, None, client_id, client_secret, refresh_token);
+/// let mut download = client.download_attachment("Leads", "ZOHO_ID_HERE", "ATTACHMENT_ID_HERE").unwrap();
+///
+/// let file_name = download.file_name().unwrap_or_else(|| String::from("attachment"));
+/// let mut file = std::fs::File::create(file_name).unwrap();
+///
+/// std::io::copy(&mut download, &mut file).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct Download {
+    response: reqwest::Response,
+}
+
+impl Download {
+    pub(crate) fn new(response: reqwest::Response) -> Download {
+        Download { response }
+    }
+
+    /// Get the MIME type of the file, if Zoho sent one.
+    pub fn content_type(&self) -> Option<&str> {
+        self.header("Content-Type")
+    }
+
+    /// Get the size of the file in bytes, if Zoho sent one.
+    pub fn content_length(&self) -> Option<u64> {
+        self.response.content_length()
+    }
+
+    /// Get the name of the file, if Zoho sent one.
+    pub fn file_name(&self) -> Option<String> {
+        self.header("Content-Disposition").and_then(parse_file_name)
+    }
+
+    fn header(&self, name: &str) -> Option<&str> {
+        self.response
+            .headers()
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+    }
+}
+
+impl Read for Download {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.response.read(buf)
+    }
+}
+
+/// Read the file name out of a `Content-Disposition` header, such as
+/// `attachment; filename="report.pdf"`.
+fn parse_file_name(disposition: &str) -> Option<String> {
+    disposition
+        .split(';')
+        .map(|param| param.trim())
+        .find_map(|param| {
+            if let Some(name) = param.strip_prefix("filename=") {
+                Some(name.trim_matches('"').to_string())
+            } else if let Some(name) = param.strip_prefix("filename*=") {
+                // extended notation: `filename*=UTF-8''report.pdf`
+                name.rsplit('\'').next().map(|name| name.to_string())
+            } else {
+                None
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quoted_file_name() {
+        assert_eq!(parse_file_name(r#"attachment; filename="report.pdf""#), Some(String::from("report.pdf")));
+    }
+
+    #[test]
+    fn extended_file_name() {
+        assert_eq!(parse_file_name("attachment; filename*=UTF-8''report.pdf"), Some(String::from("report.pdf")));
+    }
+
+    #[test]
+    fn missing_file_name() {
+        assert_eq!(parse_file_name("inline"), None);
+    }
+}
@@ -0,0 +1,283 @@
+//! Types used with the Bulk Read methods of the [`Client`](../struct.Client.html).
+//!
+//! Bulk Read jobs export up to 200,000 records per page into a zipped CSV file, which is far
+//! cheaper than paging through records with [`get_many()`](../struct.Client.html#method.get_many).
+//!
+//! You can read more about Bulk Read jobs here:
+//! [https://www.zoho.com/crm/developer/docs/api/bulk-read/overview.html](https://www.zoho.com/crm/developer/docs/api/bulk-read/overview.html)
+
+use crate::client_error::ClientError;
+use crate::criteria::Criteria;
+use crate::response::Lookup;
+use serde::{Deserialize, Serialize, Serializer};
+use serde_json::{json, Value};
+use std::fs::File;
+use std::io::{Read, Seek};
+use zip::read::ZipFile;
+use zip::ZipArchive;
+
+/// The records to export with the
+/// [`create_bulk_read()`](../struct.Client.html#method.create_bulk_read) method.
+///
+/// ### Example
+///
+/// ```
+/// use zoho_crm::Criteria;
+/// use zoho_crm::bulk_read::BulkReadQuery;
+///
+/// let query = BulkReadQuery {
+///     fields: vec![String::from("Last_Name"), String::from("Email")],
+///     criteria: Some(Criteria::equals("Lead_Source", "Web")),
+///     ..BulkReadQuery::new("Leads")
+/// };
+/// ```
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct BulkReadQuery {
+    pub module: String,
+
+    /// API names of the fields to export. All fields are exported when this is empty.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<String>,
+
+    #[serde(serialize_with = "serialize_criteria", skip_serializing_if = "Option::is_none")]
+    pub criteria: Option<Criteria>,
+
+    /// ID of the custom view to export records from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cvid: Option<String>,
+
+    /// Page of 200,000 records to export, starting at 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<usize>,
+}
+
+impl BulkReadQuery {
+    /// Create a query exporting every field of every record in a module.
+    pub fn new(module: &str) -> BulkReadQuery {
+        BulkReadQuery {
+            module: module.to_string(),
+            ..BulkReadQuery::default()
+        }
+    }
+}
+
+/// Bulk Read jobs take criteria as JSON, rather than the expression used when searching.
+fn serialize_criteria<S: Serializer>(criteria: &Option<Criteria>, serializer: S) -> Result<S::Ok, S::Error> {
+    match criteria {
+        Some(criteria) => criteria_json(criteria).serialize(serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn criteria_json(criteria: &Criteria) -> Value {
+    match criteria {
+        Criteria::Condition { field, comparator, values } => {
+            let comparator = match comparator.as_str() {
+                "equals" => "equal",
+                comparator => comparator,
+            };
+
+            // lists of values are only accepted by comparators such as `in` and `between`
+            let value = match comparator {
+                "in" | "not_in" | "between" | "not_between" => json!(values),
+                _ => json!(values.join(",")),
+            };
+
+            json!({"api_name": field, "comparator": comparator, "value": value})
+        },
+        Criteria::And(left, right) => json!({
+            "group_operator": "and",
+            "group": [criteria_json(left), criteria_json(right)],
+        }),
+        Criteria::Or(left, right) => json!({
+            "group_operator": "or",
+            "group": [criteria_json(left), criteria_json(right)],
+        }),
+    }
+}
+
+/// A Bulk Read job, as returned when creating the job or fetching its progress.
+#[derive(Debug, Deserialize)]
+pub struct BulkReadJob {
+    pub id: String,
+
+    /// State of the job, such as `ADDED`, `IN PROGRESS`, `COMPLETED` or `FAILURE`.
+    pub state: String,
+
+    pub created_by: Option<Lookup>,
+    pub created_time: Option<String>,
+
+    /// Only set once the job has completed.
+    pub result: Option<BulkReadJobResult>,
+}
+
+impl BulkReadJob {
+    /// Whether the job has stopped running, either because it completed or failed.
+    pub fn is_finished(&self) -> bool {
+        self.state == "COMPLETED" || self.state == "FAILURE"
+    }
+}
+
+/// Details of the file a completed Bulk Read job exported.
+#[derive(Debug, Deserialize)]
+pub struct BulkReadJobResult {
+    pub page: usize,
+
+    /// Number of records in the file.
+    pub count: usize,
+
+    pub per_page: usize,
+    pub download_url: String,
+
+    /// Whether there are more records to export, by creating a job for the next page.
+    pub more_records: bool,
+}
+
+/// Response sent back when a Bulk Read job is created.
+#[derive(Debug, Deserialize)]
+pub(crate) struct BulkReadCreateResponse {
+    pub data: Vec<BulkReadCreateResponseItem>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct BulkReadCreateResponseItem {
+    pub code: String,
+
+    /// The created job on success, or details of the error.
+    pub details: Value,
+    pub message: String,
+    pub status: String,
+}
+
+/// The zipped CSV file exported by a Bulk Read job, returned by the
+/// [`download_bulk_read()`](../struct.Client.html#method.download_bulk_read) method.
+///
+/// ### Example
+///
+/// ```no_run
+/// # use serde::Deserialize;
+/// # use zoho_crm::Client;
+/// # let client_id = String::from("");
+/// # let client_secret = String::from("");
+/// # let refresh_token = String::from("");
+/// # let mut client = Client::with_creds(None, None, client_id, client_secret, refresh_token);
+/// #[derive(Debug, Deserialize)]
+/// struct Lead {
+///     #[serde(rename = "Id")]
+///     id: String,
+///
+///     #[serde(rename = "Last_Name")]
+///     last_name: String,
+/// }
+///
+/// let mut result = client.download_bulk_read("BULK_READ_JOB_ID_HERE").unwrap();
+///
+/// for lead in result.rows::<Lead>().unwrap() {
+///     println!("{:?}", lead.unwrap());
+/// }
+/// ```
+#[derive(Debug)]
+pub struct BulkReadResult<R = File> {
+    archive: ZipArchive<R>,
+}
+
+impl<R: Read + Seek> BulkReadResult<R> {
+    /// Open the zipped CSV file of a Bulk Read job, such as one saved to disk earlier.
+    pub fn new(reader: R) -> Result<BulkReadResult<R>, ClientError> {
+        Ok(BulkReadResult {
+            archive: ZipArchive::new(reader)?,
+        })
+    }
+
+    /// Iterate over the rows of the CSV file, deserialized by their column headers.
+    ///
+    /// Columns are named after the API names of the exported fields, apart from the record
+    /// ID, which is named `Id`.
+    pub fn rows<T: serde::de::DeserializeOwned>(&mut self) -> Result<BulkReadRows<'_, T>, ClientError> {
+        let file = self.archive.by_index(0)?;
+
+        Ok(BulkReadRows {
+            rows: csv::Reader::from_reader(file).into_deserialize(),
+        })
+    }
+}
+
+/// Iterator over the rows of a [`BulkReadResult`](struct.BulkReadResult.html).
+pub struct BulkReadRows<'a, T> {
+    rows: csv::DeserializeRecordsIntoIter<ZipFile<'a>, T>,
+}
+
+impl<'a, T: serde::de::DeserializeOwned> Iterator for BulkReadRows<'a, T> {
+    type Item = Result<T, ClientError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.rows.next().map(|row| row.map_err(ClientError::from))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_query() {
+        let query = BulkReadQuery {
+            fields: vec![String::from("Last_Name")],
+            criteria: Some(
+                Criteria::equals("Lead_Source", "Web")
+                    .and(Criteria::one_of("Lead_Status", vec!["New", "Contacted"]))
+            ),
+            page: Some(2),
+            ..BulkReadQuery::new("Leads")
+        };
+
+        assert_eq!(
+            serde_json::to_value(&query).unwrap(),
+            json!({
+                "module": "Leads",
+                "fields": ["Last_Name"],
+                "criteria": {
+                    "group_operator": "and",
+                    "group": [
+                        {"api_name": "Lead_Source", "comparator": "equal", "value": "Web"},
+                        {"api_name": "Lead_Status", "comparator": "in", "value": ["New", "Contacted"]},
+                    ],
+                },
+                "page": 2,
+            })
+        );
+    }
+
+    #[test]
+    fn serialize_module_only() {
+        let query = BulkReadQuery::new("Leads");
+
+        assert_eq!(serde_json::to_string(&query).unwrap(), r#"{"module":"Leads"}"#);
+    }
+
+    #[test]
+    fn read_currency_rows() {
+        use crate::Currency;
+        use std::io::{Cursor, Write};
+
+        #[derive(Deserialize)]
+        struct Deal {
+            #[serde(rename = "Id")]
+            id: String,
+            #[serde(rename = "Amount")]
+            amount: Currency,
+        }
+
+        let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        writer.start_file("554023000000000001.csv", zip::write::FileOptions::default()).unwrap();
+        writer.write_all(b"Id,Amount\n554023000000000002,1234.56\n554023000000000003,100\n").unwrap();
+        let bytes = writer.finish().unwrap().into_inner();
+
+        let mut result = BulkReadResult::new(Cursor::new(bytes)).unwrap();
+        let deals: Vec<Deal> = result.rows().unwrap().collect::<Result<_, _>>().unwrap();
+
+        assert_eq!(deals[0].id, "554023000000000002");
+        assert_eq!(deals[0].amount.to_string(), "1234.56");
+        assert_eq!(deals[1].amount.to_string(), "100");
+    }
+}
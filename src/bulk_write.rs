@@ -0,0 +1,441 @@
+//! Types used with the Bulk Write methods of the [`Client`](../struct.Client.html).
+//!
+//! Bulk Write jobs insert, update or upsert up to 25,000 records from a zipped CSV file,
+//! which is far cheaper than sending records in batches of 100 with
+//! [`insert()`](../struct.Client.html#method.insert).
+//!
+//! You can read more about Bulk Write jobs here:
+//! [https://www.zoho.com/crm/developer/docs/api/bulk-write/overview.html](https://www.zoho.com/crm/developer/docs/api/bulk-write/overview.html)
+
+use crate::client_error::ClientError;
+use crate::response::Lookup;
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use zip::read::ZipFile;
+use zip::write::FileOptions;
+use zip::{ZipArchive, ZipWriter};
+
+/// What a Bulk Write job does with each row of its file.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BulkWriteOperation {
+    Insert,
+
+    /// Update existing records, found by the `find_by` field of the job.
+    Update,
+
+    /// Update existing records found by the `find_by` field of the job, and insert the rest.
+    Upsert,
+}
+
+/// A zipped CSV file, ready to be uploaded with the
+/// [`upload_bulk_write()`](../struct.Client.html#method.upload_bulk_write) method.
+///
+/// Files can hold up to 25,000 records, so they are written to a temporary file rather than
+/// kept in memory. The temporary file is removed once it has been uploaded or dropped.
+///
+/// ### Example
+///
+/// ```
+/// use serde::Serialize;
+/// use zoho_crm::bulk_write::BulkWriteFile;
+///
+/// #[derive(Serialize)]
+/// struct Lead {
+///     #[serde(rename = "Last_Name")]
+///     last_name: String,
+///
+///     #[serde(rename = "Email")]
+///     email: String,
+/// }
+///
+/// let leads = vec![
+///     Lead { last_name: String::from("Burns"), email: String::from("burns@example.com") },
+///     Lead { last_name: String::from("Smithers"), email: String::from("smithers@example.com") },
+/// ];
+///
+/// let file = BulkWriteFile::from_records(leads).unwrap();
+///
+/// assert_eq!(file.columns(), &["Last_Name", "Email"]);
+/// ```
+#[derive(Debug)]
+pub struct BulkWriteFile {
+    columns: Vec<String>,
+    file: File,
+    len: u64,
+}
+
+impl BulkWriteFile {
+    /// Build a file from serializable records, with one column per field. The column headers
+    /// should be the API names of the fields.
+    ///
+    /// Records must serialize as structs; maps and nested values are not supported.
+    pub fn from_records<I, T>(records: I) -> Result<BulkWriteFile, ClientError>
+        where I: IntoIterator<Item = T>, T: Serialize
+    {
+        let mut records = records.into_iter().peekable();
+
+        // the header row is only known once a record has been serialized
+        let columns = match records.peek() {
+            Some(record) => {
+                let mut header = csv::Writer::from_writer(Vec::new());
+                header.serialize(record)?;
+
+                let header = header.into_inner().map_err(|err| ClientError::from(err.to_string()))?;
+
+                csv_columns(csv::Reader::from_reader(header.as_slice()).headers()?)
+            },
+            None => Vec::new(),
+        };
+
+        BulkWriteFile::write(columns, |writer| {
+            for record in records {
+                writer.serialize(record)?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Build a file from CSV data, with a header row naming the API name of each column's
+    /// field.
+    pub fn from_csv<R: Read>(reader: R) -> Result<BulkWriteFile, ClientError> {
+        let mut reader = csv::Reader::from_reader(reader);
+        let headers = reader.headers()?.clone();
+
+        BulkWriteFile::write(csv_columns(&headers), |writer| {
+            writer.write_record(&headers)?;
+
+            for record in reader.byte_records() {
+                writer.write_byte_record(&record?)?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Zip the CSV rows written by `write_rows` into a temporary file.
+    fn write<F>(columns: Vec<String>, write_rows: F) -> Result<BulkWriteFile, ClientError>
+        where F: FnOnce(&mut csv::Writer<&mut ZipWriter<File>>) -> Result<(), ClientError>
+    {
+        let mut zipped = ZipWriter::new(tempfile::tempfile()?);
+        zipped.start_file("records.csv", FileOptions::default())?;
+
+        {
+            let mut writer = csv::Writer::from_writer(&mut zipped);
+            write_rows(&mut writer)?;
+            writer.flush()?;
+        }
+
+        let mut file = zipped.finish()?;
+        let len = file.seek(SeekFrom::End(0))?;
+        file.seek(SeekFrom::Start(0))?;
+
+        Ok(BulkWriteFile { columns, file, len })
+    }
+
+    /// Get the column headers of the file.
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+
+    /// Map each column of the file to the field it names.
+    pub fn field_mappings(&self) -> Vec<FieldMapping> {
+        self.columns
+            .iter()
+            .enumerate()
+            .map(|(index, column)| FieldMapping::new(column, index))
+            .collect()
+    }
+
+    /// Get the zipped file, and its length in bytes.
+    pub(crate) fn into_reader(self) -> (File, u64) {
+        (self.file, self.len)
+    }
+}
+
+fn csv_columns(headers: &csv::StringRecord) -> Vec<String> {
+    headers.iter().map(|column| column.to_string()).collect()
+}
+
+/// Maps a column of the uploaded file to a field of the module.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct FieldMapping {
+    pub api_name: String,
+
+    /// Position of the column in the file, starting at 0.
+    pub index: usize,
+
+    /// For lookup fields, the field of the looked up record the column matches.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub find_by: Option<String>,
+
+    /// For date and time fields, the format the column is written in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+}
+
+impl FieldMapping {
+    pub fn new(api_name: &str, index: usize) -> FieldMapping {
+        FieldMapping {
+            api_name: api_name.to_string(),
+            index,
+            find_by: None,
+            format: None,
+        }
+    }
+}
+
+/// Parameters for creating a Bulk Write job with the
+/// [`create_bulk_write()`](../struct.Client.html#method.create_bulk_write) method.
+#[derive(Clone, Debug)]
+pub struct BulkWriteParams {
+    pub operation: BulkWriteOperation,
+    pub module: String,
+
+    /// ID of the uploaded file, returned by the
+    /// [`upload_bulk_write()`](../struct.Client.html#method.upload_bulk_write) method.
+    pub file_id: String,
+
+    /// API name of a unique field used to find existing records. Required for updates
+    /// and upserts.
+    pub find_by: Option<String>,
+
+    pub field_mappings: Vec<FieldMapping>,
+}
+
+/// Body sent to create a Bulk Write job.
+#[derive(Debug, Serialize)]
+pub(crate) struct BulkWriteRequest<'a> {
+    pub operation: BulkWriteOperation,
+    pub resource: Vec<BulkWriteRequestResource<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct BulkWriteRequestResource<'a> {
+    #[serde(rename = "type")]
+    pub resource_type: &'a str,
+
+    pub module: &'a str,
+    pub file_id: &'a str,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub find_by: Option<&'a str>,
+
+    pub field_mappings: &'a [FieldMapping],
+}
+
+impl<'a> From<&'a BulkWriteParams> for BulkWriteRequest<'a> {
+    fn from(params: &'a BulkWriteParams) -> Self {
+        BulkWriteRequest {
+            operation: params.operation,
+            resource: vec![BulkWriteRequestResource {
+                resource_type: "data",
+                module: &params.module,
+                file_id: &params.file_id,
+                find_by: params.find_by.as_deref(),
+                field_mappings: &params.field_mappings,
+            }],
+        }
+    }
+}
+
+/// Response sent back when a file is uploaded, or a Bulk Write job is created.
+#[derive(Debug, Deserialize)]
+pub(crate) struct BulkWriteResponse {
+    pub details: BulkWriteResponseDetails,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct BulkWriteResponseDetails {
+    pub file_id: Option<String>,
+    pub id: Option<String>,
+}
+
+/// A Bulk Write job, as returned when fetching its progress.
+#[derive(Debug, Deserialize)]
+pub struct BulkWriteJob {
+    pub id: String,
+
+    /// State of the job, such as `ADDED`, `INPROGRESS`, `COMPLETED` or `FAILED`.
+    pub status: String,
+
+    pub operation: Option<String>,
+    pub created_by: Option<Lookup>,
+    pub created_time: Option<String>,
+
+    #[serde(default)]
+    pub resource: Vec<BulkWriteJobResource>,
+
+    /// Only set once the job has completed.
+    pub result: Option<BulkWriteJobResult>,
+}
+
+impl BulkWriteJob {
+    /// Whether the job has stopped running, either because it completed or failed.
+    pub fn is_finished(&self) -> bool {
+        self.status == "COMPLETED" || self.status == "FAILED"
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BulkWriteJobResource {
+    pub status: String,
+    pub module: String,
+    pub file: Option<BulkWriteJobFile>,
+}
+
+/// Counts of the rows of the uploaded file, once it has been processed.
+#[derive(Debug, Deserialize)]
+pub struct BulkWriteJobFile {
+    pub status: String,
+    pub name: Option<String>,
+
+    #[serde(default)]
+    pub total_count: usize,
+
+    #[serde(default)]
+    pub added_count: usize,
+
+    #[serde(default)]
+    pub updated_count: usize,
+
+    #[serde(default)]
+    pub skipped_count: usize,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BulkWriteJobResult {
+    /// Full URL of the result file, to pass to the
+    /// [`download_bulk_write()`](../struct.Client.html#method.download_bulk_write) method.
+    pub download_url: String,
+}
+
+/// The outcome of one row of the uploaded file.
+#[derive(Debug, Deserialize)]
+pub struct BulkWriteRow {
+    /// Outcome of the row, such as `ADDED`, `UPDATED` or `SKIPPED`.
+    #[serde(rename = "STATUS")]
+    pub status: String,
+
+    /// ID of the record that was added or updated.
+    #[serde(rename = "RECORD_ID", default)]
+    pub record_id: Option<String>,
+
+    /// Why the row was skipped.
+    #[serde(rename = "ERRORS", default)]
+    pub errors: Option<String>,
+}
+
+/// The zipped CSV result file of a Bulk Write job, returned by the
+/// [`download_bulk_write()`](../struct.Client.html#method.download_bulk_write) method.
+///
+/// The file holds the rows of the uploaded file, in the same order, with the outcome of each
+/// row added in `STATUS`, `RECORD_ID` and `ERRORS` columns.
+#[derive(Debug)]
+pub struct BulkWriteResult<R = File> {
+    archive: ZipArchive<R>,
+}
+
+impl<R: Read + Seek> BulkWriteResult<R> {
+    /// Open the zipped result file of a Bulk Write job, such as one saved to disk earlier.
+    pub fn new(reader: R) -> Result<BulkWriteResult<R>, ClientError> {
+        Ok(BulkWriteResult {
+            archive: ZipArchive::new(reader)?,
+        })
+    }
+
+    /// Iterate over the outcome of each row.
+    pub fn rows(&mut self) -> Result<BulkWriteRows<'_>, ClientError> {
+        let file = self.archive.by_index(0)?;
+
+        Ok(BulkWriteRows {
+            rows: csv::Reader::from_reader(file).into_deserialize(),
+        })
+    }
+}
+
+/// Iterator over the rows of a [`BulkWriteResult`](struct.BulkWriteResult.html).
+pub struct BulkWriteRows<'a> {
+    rows: csv::DeserializeRecordsIntoIter<ZipFile<'a>, BulkWriteRow>,
+}
+
+impl<'a> Iterator for BulkWriteRows<'a> {
+    type Item = Result<BulkWriteRow, ClientError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.rows.next().map(|row| row.map_err(ClientError::from))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_from_records() {
+        #[derive(Serialize)]
+        struct Lead {
+            #[serde(rename = "Last_Name")]
+            last_name: &'static str,
+
+            #[serde(rename = "Email")]
+            email: Option<&'static str>,
+        }
+
+        let leads = vec![
+            Lead { last_name: "Burns", email: Some("burns@example.com") },
+            Lead { last_name: "Smithers", email: None },
+        ];
+
+        let file = BulkWriteFile::from_records(leads).unwrap();
+        let field_mappings = file.field_mappings();
+
+        assert_eq!(read_csv(file), "Last_Name,Email\nBurns,burns@example.com\nSmithers,\n");
+        assert_eq!(field_mappings, vec![FieldMapping::new("Last_Name", 0), FieldMapping::new("Email", 1)]);
+    }
+
+    #[test]
+    fn file_from_csv() {
+        let file = BulkWriteFile::from_csv("Last_Name,Email\nBurns,\"burns, montgomery\"\n".as_bytes()).unwrap();
+
+        assert_eq!(file.columns(), &["Last_Name", "Email"]);
+        assert_eq!(read_csv(file), "Last_Name,Email\nBurns,\"burns, montgomery\"\n");
+    }
+
+    #[test]
+    fn file_without_records() {
+        let file = BulkWriteFile::from_records(Vec::<FieldMapping>::new()).unwrap();
+
+        assert!(file.columns().is_empty());
+    }
+
+    /// Unzip the CSV data of a file.
+    fn read_csv(file: BulkWriteFile) -> String {
+        let (file, len) = file.into_reader();
+        assert_eq!(file.metadata().unwrap().len(), len);
+
+        let mut archive = ZipArchive::new(file).unwrap();
+        let mut csv = String::new();
+        archive.by_index(0).unwrap().read_to_string(&mut csv).unwrap();
+
+        csv
+    }
+
+    #[test]
+    fn serialize_params() {
+        let params = BulkWriteParams {
+            operation: BulkWriteOperation::Upsert,
+            module: String::from("Leads"),
+            file_id: String::from("100"),
+            find_by: Some(String::from("Email")),
+            field_mappings: vec![FieldMapping::new("Email", 0)],
+        };
+
+        assert_eq!(
+            serde_json::to_string(&BulkWriteRequest::from(&params)).unwrap(),
+            r#"{"operation":"upsert","resource":[{"type":"data","module":"Leads","file_id":"100","find_by":"Email","field_mappings":[{"api_name":"Email","index":0}]}]}"#
+        );
+    }
+}
@@ -1,12 +1,25 @@
+use crate::attachments::{Attachment, Download, Photo};
+use crate::bulk_read::{BulkReadCreateResponse, BulkReadJob, BulkReadQuery, BulkReadResult};
+use crate::bulk_write::{BulkWriteFile, BulkWriteJob, BulkWriteParams, BulkWriteRequest, BulkWriteResponse, BulkWriteResult};
 use crate::client_error::ClientError;
+use crate::currency::{BaseCurrencyResponse, CurrenciesResponse, CurrencyData, OrgCurrency};
+use crate::leads::{ConvertLeadOptions, ConvertLeadResponseItem, ConvertedLead};
+use crate::mass_update::{MassUpdateRequest, MassUpdateResponse, MassUpdateStatus, MassUpdateTarget};
+use crate::metadata::{CustomView, CustomViewsResponse, FieldMeta, FieldsResponse, Layout, LayoutsResponse, ModuleMeta, ModulesResponse};
+use crate::notes::{Note, NoteData};
+use crate::org::{Org, OrgResponse, Profile, ProfilesResponse, Role, RolesResponse, TerritoriesResponse, Territory};
+use crate::pagination::{RecordIter, RecordStream};
+use crate::params::{self, DeletedParams, ListParams};
+use crate::search::{CountFilter, SearchParams};
+use crate::tags::{RecordTagsResponse, TagActionResponse, TagsResponse};
 use crate::token_record::TokenRecord;
-use reqwest;
+use crate::users::{User, UserActionResponse, UserData, UsersParams, UsersResponse};
 use crate::response;
+use reqwest::multipart;
 use std::collections::HashMap;
-use std::time::Duration;
-
-#[cfg(test)]
-use mockito;
+use std::io::{self, Read, Seek, SeekFrom};
+use std::thread;
+use std::time::{Duration, Instant};
 
 /// Default network timeout for API requests.
 const DEFAULT_TIMEOUT: u64 = 30;
@@ -190,6 +203,135 @@ impl Client {
         }
     }
 
+    /// Build an authorized request against the CRM API. A new access token is fetched first if
+    /// one has not been set.
+    ///
+    /// `path` is appended to the API domain, and should begin with a forward slash.
+    fn request(&mut self, method: reqwest::Method, path: &str) -> Result<reqwest::RequestBuilder, ClientError> {
+        let api_domain = self.authorized_api_domain()?;
+
+        self.request_url(method, &format!("{}{}", api_domain, path))
+    }
+
+    /// Build a request for a full URL, such as a download link sent by Zoho. A new access
+    /// token is fetched first if one has not been set.
+    ///
+    /// The access token is only sent to Zoho hosts, and the host of the API domain.
+    fn request_url(&mut self, method: reqwest::Method, url: &str) -> Result<reqwest::RequestBuilder, ClientError> {
+        let api_domain = self.authorized_api_domain()?;
+        let url = reqwest::Url::parse(url)?;
+
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(self.timeout))
+            .build()?;
+
+        let trusted = match url.host_str() {
+            Some(host) => is_zoho_host(host) || reqwest::Url::parse(&api_domain)?.host_str() == Some(host),
+            None => false,
+        };
+
+        let request = client.request(method, url);
+
+        if !trusted {
+            return Ok(request);
+        }
+
+        // we are guaranteed a token when we reach this line
+        let token = self.access_token().unwrap();
+
+        Ok(request.header("Authorization", format!("Zoho-oauthtoken {}", token)))
+    }
+
+    /// Get the API domain, after fetching a new access token if one has not been set.
+    fn authorized_api_domain(&mut self) -> Result<String, ClientError> {
+        if self.access_token.is_none() {
+            self.get_new_token()?;
+        }
+
+        match self.api_domain() {
+            Some(api_domain) => Ok(api_domain),
+            None => Err(ClientError::from("No API domain set")),
+        }
+    }
+
+    /// Get the domain files are uploaded to, which changes depending on the current environment.
+    ///
+    /// This is primarily used to allow for HTTP test mocking of uploads.
+    fn get_content_domain(api_domain: &str) -> Result<String, ClientError> {
+        #[cfg(test)]
+        return Ok(api_domain.to_string());
+
+        #[cfg(not(test))]
+        return content_domain(api_domain);
+    }
+
+    /// Build an authorized `GET` request for a paginated list, after validating its parameters.
+    fn list_request(&mut self, path: &str, params: Option<ListParams>) -> Result<reqwest::RequestBuilder, ClientError> {
+        let params = params.unwrap_or_default();
+        params.validate()?;
+
+        let mut request = self.request(reqwest::Method::GET, path)?.query(&params);
+
+        if let Some(modified_since) = &params.modified_since {
+            request = request.header("If-Modified-Since", modified_since.as_str());
+        }
+
+        Ok(request)
+    }
+
+    /// Upload a file as the `file` part of a multipart form, and parse the response into `T`.
+    fn multipart_upload<T: serde::de::DeserializeOwned>(&mut self, path: &str, part: multipart::Part) -> Result<T, ClientError> {
+        let form = multipart::Form::new().part("file", part);
+        let request = self.request(reqwest::Method::POST, path)?.multipart(form);
+
+        Client::send(request)
+    }
+
+    /// Send a request and parse the response body into `T`.
+    fn send<T: serde::de::DeserializeOwned>(request: reqwest::RequestBuilder) -> Result<T, ClientError> {
+        let mut response = request.send()?;
+        let raw_response = response.text()?;
+
+        parse_response(&raw_response)
+    }
+
+    /// Send a request and parse the response body into `T`.
+    ///
+    /// Zoho answers with a `204 No Content` when there are no records to return, or a
+    /// `304 Not Modified` when no records changed since the `If-Modified-Since` header. In
+    /// either case, `None` is returned instead of an error.
+    fn send_optional<T: serde::de::DeserializeOwned>(request: reqwest::RequestBuilder) -> Result<Option<T>, ClientError> {
+        let mut response = request.send()?;
+
+        match response.status() {
+            reqwest::StatusCode::NO_CONTENT | reqwest::StatusCode::NOT_MODIFIED => return Ok(None),
+            _ => (),
+        }
+
+        let raw_response = response.text()?;
+
+        parse_response(&raw_response).map(Some)
+    }
+
+    /// Send a request for a file, which is streamed back instead of parsed.
+    ///
+    /// Zoho only sends back JSON when the request failed, in which case the error is parsed
+    /// the same way as any other response.
+    fn send_download(request: reqwest::RequestBuilder) -> Result<Download, ClientError> {
+        let mut response = request.send()?;
+
+        if response.status().is_success() {
+            return Ok(Download::new(response));
+        }
+
+        let raw_response = response.text()?;
+
+        match parse_response::<serde_json::Value>(&raw_response) {
+            Err(error) => Err(error),
+            Ok(_) => Err(ClientError::UnexpectedResponseType(raw_response)),
+        }
+    }
+
     /// Fetches a record from Zoho.
     ///
     /// Zoho returns a data array with this method, even though that array will always be of
@@ -223,38 +365,49 @@ impl Client {
     /// assert_eq!(account.name, "Account name");
     /// ```
     pub fn get<T: serde::de::DeserializeOwned>(&mut self, module: &str, id: &str) -> Result<response::ApiGetResponse<T>, ClientError> {
-        if self.access_token.is_none() {
-            self.get_new_token()?;
-        }
-
-        // we are guaranteed a token when we reach this line
-        let token = self.access_token.clone().unwrap();
+        let request = self.request(reqwest::Method::GET, &format!("/crm/v2/{}/{}", module, id))?;
 
-        let timeout = Duration::from_secs(self.timeout);
-        let client = reqwest::Client::builder().timeout(timeout).build()?;
+        Client::send(request)
+    }
 
-        let url = format!("{}/crm/v2/{}/{}", self.api_domain().unwrap(), module, id);
+    /// Fetches a record from Zoho, with only the given fields.
+    ///
+    /// This works the same as [`get()`](struct.Client.html#method.get), but cuts the size of
+    /// the response on modules with many fields. The fields a type needs can be read from the
+    /// type itself with [`field_names()`](fn.field_names.html).
+    ///
+    /// Every field is returned when `fields` is empty. Zoho accepts at most 50 fields.
+    ///
+    /// ### Example
+    ///
+    /// ```no_run
+    /// # use serde::Deserialize;
+    /// use zoho_crm::{field_names, Client};
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Account {
+    ///     #[serde(rename = "Account_Name")]
+    ///     name: String,
+    /// }
+    ///
+    /// # let client_id = String::from("");
+    /// # let client_secret = String::from("");
+    /// # let refresh_token = String::from("");
+    /// let mut client = Client::with_creds(None, None, client_id, client_secret, refresh_token);
+    ///
+    /// let fields = field_names::<Account>();
+    /// let response = client.get_with_fields::<Account>("Accounts", "ZOHO_ID_HERE", fields).unwrap();
+    /// ```
+    pub fn get_with_fields<T: serde::de::DeserializeOwned>(&mut self, module: &str, id: &str, fields: &[&str]) -> Result<response::ApiGetResponse<T>, ClientError> {
+        params::validate_fields(fields.len())?;
 
-        let mut response = client
-            .get(url.as_str())
-            .header("Authorization", format!("Zoho-oauthtoken {}", token))
-            .send()?;
-        let raw_response = response.text()?;
+        let mut request = self.request(reqwest::Method::GET, &format!("/crm/v2/{}/{}", module, id))?;
 
-        if let Ok(response) = serde_json::from_str::<response::ApiErrorResponse>(&raw_response) {
-            return Err(ClientError::ApiError(response));
+        if !fields.is_empty() {
+            request = request.query(&[("fields", fields.join(","))]);
         }
 
-        match serde_json::from_str::<response::ApiGetResponse<T>>(&raw_response) {
-            Ok(data) => Ok(data),
-            Err(_) => {
-                if raw_response.len() > 0 {
-                    Err(ClientError::UnexpectedResponseType(raw_response))
-                } else {
-                    Err(ClientError::General(String::from("Empty response")))
-                }
-            },
-        }
+        Client::send(request)
     }
 
     /// Fetches a page of records from Zoho.
@@ -262,6 +415,11 @@ impl Client {
     /// Zoho API function documentation:
     /// [https://www.zoho.com/crm/developer/docs/api/get-records.html](https://www.zoho.com/crm/developer/docs/api/get-records.html)
     ///
+    /// If the module has no records, an empty data array is returned rather than an error. The
+    /// same goes for when `modified_since` is set in the parameters, and no records changed
+    /// since then. To go through every page of records, use
+    /// [`iter_all`](struct.Client.html#method.iter_all).
+    ///
     /// ### Example
     ///
     /// ```no_run
@@ -286,8 +444,7 @@ impl Client {
     ///
     /// ```no_run
     /// # use serde::Deserialize;
-    /// # use std::collections::HashMap;
-    /// use zoho_crm::{parse_params, Client};
+    /// use zoho_crm::{Client, ListParams};
     ///
     /// #[derive(Deserialize)]
     /// struct Account {
@@ -300,710 +457,3946 @@ impl Client {
     ///
     /// # let mut client = Client::with_creds(None, None, client_id, client_secret, refresh_token);
     ///
-    /// let mut params: HashMap<&str, &str> = HashMap::new();
-    /// params.insert("cvid", "YOUR_VIEW_ID_HERE");
-    /// params.insert("page", "2");
-    /// params.insert("per_page", "50");
+    /// let params = ListParams {
+    ///     cvid: Some(String::from("YOUR_VIEW_ID_HERE")),
+    ///     page: Some(2),
+    ///     per_page: Some(50),
+    ///     ..ListParams::default()
+    /// };
     ///
-    /// let params = parse_params(params).unwrap();
     /// let accounts = client.get_many::<Account>("Accounts", Some(params)).unwrap();
     /// ```
-    pub fn get_many<T: serde::de::DeserializeOwned>(&mut self, module: &str, params: Option<String>) -> Result<response::ApiGetManyResponse<T>, ClientError> {
-        if self.access_token.is_none() {
-            self.get_new_token()?;
-        }
+    ///
+    /// To only fetch the fields `Account` needs, start from
+    /// [`ListParams::for_type()`](struct.ListParams.html#method.for_type) instead.
+    pub fn get_many<T: serde::de::DeserializeOwned>(&mut self, module: &str, params: Option<ListParams>) -> Result<response::ApiGetManyResponse<T>, ClientError> {
+        let request = self.list_request(&format!("/crm/v2/{}", module), params)?;
 
-        // we are guaranteed a token when we reach this line
-        let token = self.access_token().unwrap();
-        let api_domain = self.api_domain().unwrap();
+        Ok(Client::send_optional(request)?.unwrap_or_default())
+    }
 
-        let timeout = Duration::from_secs(self.timeout);
-        let client = reqwest::Client::builder().timeout(timeout).build()?;
+    /// Iterates over every record of a module, fetching pages as needed.
+    ///
+    /// `params` takes the same parameters as [`get_many`](struct.Client.html#method.get_many),
+    /// so `modified_since` can be used to only iterate over records that changed. Iteration
+    /// starts from the given `page`, or the first page if none is given.
+    ///
+    /// ### Example
+    ///
+    /// ```no_run
+    /// # use serde::Deserialize;
+    /// use zoho_crm::{Client, ListParams};
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Account {
+    ///     name: String,
+    /// }
+    ///
+    /// # let client_id = String::from("");
+    /// # let client_secret = String::from("");
+    /// # let refresh_token = String::from("");
+    /// let mut client = Client::with_creds(None, None, client_id, client_secret, refresh_token);
+    ///
+    /// let params = ListParams {
+    ///     per_page: Some(200),
+    ///     ..ListParams::default()
+    /// };
+    ///
+    /// for account in client.iter_all::<Account>("Accounts", Some(params)) {
+    ///     println!("{}", account.unwrap().name);
+    /// }
+    /// ```
+    pub fn iter_all<T: serde::de::DeserializeOwned>(&mut self, module: &str, params: Option<ListParams>) -> RecordIter<'_, T> {
+        RecordIter::new(self, module, params)
+    }
 
-        let mut url = format!("{}/crm/v2/{}", api_domain, module);
+    /// Streams every record of a module asynchronously, fetching pages as needed.
+    ///
+    /// This takes the same parameters as [`iter_all()`](#method.iter_all), and pages through
+    /// records the same way. The stream must be run on a `tokio` runtime.
+    ///
+    /// A new access token is fetched before the stream is returned if one has not been set.
+    /// The stream does not refresh the token, so it should be consumed before the token
+    /// expires.
+    ///
+    /// ### Example
+    ///
+    /// ```no_run
+    /// # use serde::Deserialize;
+    /// use futures::{Future, Stream};
+    /// use zoho_crm::Client;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Account {
+    ///     name: String,
+    /// }
+    ///
+    /// # let client_id = String::from("");
+    /// # let client_secret = String::from("");
+    /// # let refresh_token = String::from("");
+    /// let mut client = Client::with_creds(None, None, client_id, client_secret, refresh_token);
+    ///
+    /// let accounts = client
+    ///     .stream_all::<Account>("Accounts", None)
+    ///     .for_each(|account| {
+    ///         println!("{}", account.name);
+    ///         Ok(())
+    ///     })
+    ///     .map_err(|error| eprintln!("{}", error));
+    ///
+    /// tokio::run(accounts);
+    /// ```
+    pub fn stream_all<T>(&mut self, module: &str, params: Option<ListParams>) -> RecordStream<T>
+        where T: serde::de::DeserializeOwned + Send + 'static
+    {
+        match self.prepare_stream(module, &params) {
+            Ok((http, url, token)) => RecordStream::new(http, url, token, params),
+            Err(error) => RecordStream::failed(error),
+        }
+    }
 
-        if params.is_none() == false {
-            url = url + &format!("?{}", params.unwrap());
+    /// Check the parameters of a stream, and get what it needs to fetch pages.
+    fn prepare_stream(&mut self, module: &str, params: &Option<ListParams>) -> Result<(reqwest::r#async::Client, String, String), ClientError> {
+        if let Some(params) = params {
+            params.validate()?;
         }
 
-        let mut response = client
-            .get(url.as_str())
-            .header("Authorization", String::from("Zoho-oauthtoken ") + &token)
-            .send()?;
-        let raw_response = response.text()?;
+        let api_domain = self.authorized_api_domain()?;
 
-        if let Ok(response) = serde_json::from_str::<response::ApiErrorResponse>(&raw_response) {
-            return Err(ClientError::ApiError(response));
-        }
+        // we are guaranteed a token when we reach this line
+        let token = self.access_token().unwrap();
 
-        match serde_json::from_str::<response::ApiGetManyResponse<T>>(&raw_response) {
-            Ok(data) => Ok(data),
-            Err(_) => {
-                if raw_response.len() > 0 {
-                    Err(ClientError::UnexpectedResponseType(raw_response))
-                } else {
-                    Err(ClientError::General(String::from("Empty response")))
-                }
-            },
-        }
+        let http = reqwest::r#async::Client::builder()
+            .timeout(Duration::from_secs(self.timeout))
+            .build()?;
+
+        Ok((http, format!("{}/crm/v2/{}", api_domain, module), token))
     }
 
-    /// Insert multiple records in Zoho.
+    /// Creates a Bulk Read job, which exports records into a zipped CSV file. This is a
+    /// much cheaper way to fetch large numbers of records than [`get_many()`](#method.get_many).
     ///
     /// Zoho API function documentation:
-    /// [https://www.zoho.com/crm/developer/docs/api/insert-records.html](https://www.zoho.com/crm/developer/docs/api/insert-records.html)
-    ///
-    /// It is important to note that this method *may* mask errors with a successful response.
-    /// That is because record specific errors will be shown alongside the record in the response.
-    /// We do not want to assume this is an *unsuccessful* response, and so it is up to you to
-    /// handle them.
+    /// [https://www.zoho.com/crm/developer/docs/api/bulk-read/create-job.html](https://www.zoho.com/crm/developer/docs/api/bulk-read/create-job.html)
     ///
-    /// The `params` argument accepts any serializable data type.
+    /// ### Example
     ///
     /// ```no_run
     /// # use std::collections::HashMap;
     /// # use zoho_crm::Client;
+    /// use std::time::Duration;
+    /// use zoho_crm::bulk_read::BulkReadQuery;
     /// # let client_id = String::from("");
     /// # let client_secret = String::from("");
     /// # let refresh_token = String::from("");
-    /// # let mut zoho_client = Client::with_creds(None, None, client_id, client_secret, refresh_token);
-    /// let mut record: HashMap<&str, &str> = HashMap::new();
-    /// record.insert("name", "sample");
+    /// # let mut client = Client::with_creds(None, None, client_id, client_secret, refresh_token);
     ///
-    /// let response = zoho_client.insert("Accounts", vec![record]).unwrap();
+    /// let job = client.create_bulk_read(BulkReadQuery::new("Leads")).unwrap();
+    /// let job = client
+    ///     .wait_for_bulk_read(&job.id, Duration::from_secs(10), Duration::from_secs(3600))
+    ///     .unwrap();
     ///
-    /// for record in response.data {
-    ///     match record.code.as_str() {
-    ///         "SUCCESS" => println!("Record was successful"),
-    ///         _ => println!("Record was NOT successful"),
-    ///     }
+    /// let mut result = client.download_bulk_read(&job.id).unwrap();
+    ///
+    /// for lead in result.rows::<HashMap<String, String>>().unwrap() {
+    ///     println!("{:?}", lead.unwrap());
     /// }
     /// ```
-    pub fn insert<T>(&mut self, module: &str, data: Vec<T>) -> Result<response::ApiSuccessResponse, ClientError>
-        where T: serde::ser::Serialize
-    {
-        if self.access_token.is_none() {
-           self.get_new_token()?;
-       }
-
-       // we are guaranteed a token when we reach this line
-       let token = self.access_token().unwrap();
-       let api_domain = self.api_domain().unwrap();
-
-       let client = reqwest::Client::builder()
-           .timeout(Duration::from_secs(self.timeout))
-           .build()?;
-
-       let url = format!("{}/crm/v2/{}", api_domain, module);
-
-       // Zoho requires incoming data to be sent via a `data` field
-       let mut params: HashMap<&str, Vec<T>> = HashMap::new();
-       params.insert("data", data);
-
-       let mut response = client
-           .post(url.as_str())
-           .header("Authorization", String::from("Zoho-oauthtoken ") + &token)
-           .json(&params)
-           .send()?;
-       let raw_response = response.text()?;
-
-       if let Ok(response) = serde_json::from_str::<response::ApiErrorResponse>(&raw_response) {
-           return Err(ClientError::ApiError(response));
-       }
-
-       match serde_json::from_str::<response::ApiSuccessResponse>(&raw_response) {
-           Ok(response) => Ok(response),
-           Err(_) => {
-               if raw_response.len() > 0 {
-                    Err(ClientError::UnexpectedResponseType(raw_response))
-               } else {
-                   Err(ClientError::General(String::from("Empty response")))
-               }
-           },
-       }
-   }
+    pub fn create_bulk_read(&mut self, query: BulkReadQuery) -> Result<BulkReadJob, ClientError> {
+        let mut params: HashMap<&str, BulkReadQuery> = HashMap::new();
+        params.insert("query", query);
+
+        let request = self
+            .request(reqwest::Method::POST, "/crm/bulk/v2/read")?
+            .json(&params);
+
+        let response: BulkReadCreateResponse = Client::send(request)?;
+
+        match response.data.into_iter().next() {
+            Some(item) if item.status == "error" => Err(ClientError::ApiError(response::ApiErrorResponse {
+                code: item.code,
+                message: item.message,
+                status: item.status,
+            })),
+            Some(item) => Ok(serde_json::from_value(item.details)?),
+            None => Err(ClientError::from("No job received")),
+        }
+    }
 
-    /// Updates multiple records in Zoho.
+    /// Fetches the progress of a Bulk Read job.
     ///
     /// Zoho API function documentation:
-    /// [https://www.zoho.com/crm/developer/docs/api/update-records.html](https://www.zoho.com/crm/developer/docs/api/update-records.html)
+    /// [https://www.zoho.com/crm/developer/docs/api/bulk-read/get-job-details.html](https://www.zoho.com/crm/developer/docs/api/bulk-read/get-job-details.html)
+    pub fn bulk_read_status(&mut self, job_id: &str) -> Result<BulkReadJob, ClientError> {
+        let request = self.request(reqwest::Method::GET, &format!("/crm/bulk/v2/read/{}", job_id))?;
+
+        let response: response::ApiGetResponse<BulkReadJob> = Client::send(request)?;
+
+        match response.data.into_iter().next() {
+            Some(job) => Ok(job),
+            None => Err(ClientError::from("No job received")),
+        }
+    }
+
+    /// Polls the progress of a Bulk Read job every `interval`, until the job is finished.
     ///
-    /// It is important to note that this method *may* mask errors with a successful response.
-    /// That is because record specific errors will be shown alongside the record in the response.
-    /// We do not want to assume this is an *unsuccessful* response, and so it is up to you to
-    /// handle them.
+    /// An error is returned if the job is still running after `timeout`.
+    pub fn wait_for_bulk_read(&mut self, job_id: &str, interval: Duration, timeout: Duration) -> Result<BulkReadJob, ClientError> {
+        poll_until(interval, timeout, || self.bulk_read_status(job_id), BulkReadJob::is_finished)?
+            .ok_or_else(|| ClientError::General(format!("Bulk Read job {} did not finish in time", job_id)))
+    }
+
+    /// Downloads the zipped CSV file exported by a completed Bulk Read job.
     ///
-    /// The `params` argument accepts any serializable data type.
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/bulk-read/download-result.html](https://www.zoho.com/crm/developer/docs/api/bulk-read/download-result.html)
+    ///
+    /// Exports can be large, so the file is spooled to a temporary file rather than kept in
+    /// memory. It is removed once the result is dropped.
+    pub fn download_bulk_read(&mut self, job_id: &str) -> Result<BulkReadResult, ClientError> {
+        let request = self.request(reqwest::Method::GET, &format!("/crm/bulk/v2/read/{}/result", job_id))?;
+
+        let mut download = Client::send_download(request)?;
+        let mut file = tempfile::tempfile()?;
+        io::copy(&mut download, &mut file)?;
+        file.seek(SeekFrom::Start(0))?;
+
+        BulkReadResult::new(file)
+    }
+
+    /// Uploads a file for a Bulk Write job, returning the ID of the file. `org_id` is the ID
+    /// of your Zoho organization.
+    ///
+    /// Files are uploaded to Zoho's content domain, such as `content.zohoapis.com`, which
+    /// matches the API domain of the client.
+    ///
+    /// Zoho has no content domain for the `crmsandbox` domain used by
+    /// [`set_sandbox()`](#method.set_sandbox), so uploads from sandbox clients return an error.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/bulk-write/upload-file.html](https://www.zoho.com/crm/developer/docs/api/bulk-write/upload-file.html)
+    ///
+    /// ### Example
     ///
     /// ```no_run
-    /// # use std::collections::HashMap;
+    /// # use serde::Serialize;
     /// # use zoho_crm::Client;
+    /// use std::time::Duration;
+    /// use zoho_crm::bulk_write::{BulkWriteFile, BulkWriteOperation, BulkWriteParams};
     /// # let client_id = String::from("");
     /// # let client_secret = String::from("");
     /// # let refresh_token = String::from("");
-    /// # let mut zoho_client = Client::with_creds(None, None, client_id, client_secret, refresh_token);
-    /// let mut record: HashMap<&str, &str> = HashMap::new();
-    /// record.insert("id", "ZOHO_RECORD_ID_HERE");
-    /// record.insert("name", "sample");
+    /// # let mut client = Client::with_creds(None, None, client_id, client_secret, refresh_token);
+    /// # #[derive(Serialize)]
+    /// # struct Lead {}
+    /// # let leads: Vec<Lead> = vec![];
     ///
-    /// let response = zoho_client.update_many("Accounts", vec![record]).unwrap();
+    /// let file = BulkWriteFile::from_records(leads).unwrap();
+    /// let field_mappings = file.field_mappings();
+    /// let file_id = client.upload_bulk_write("YOUR_ORG_ID", file).unwrap();
     ///
-    /// for record in response.data {
-    ///     match record.code.as_str() {
-    ///         "SUCCESS" => println!("Record was successful"),
-    ///         _ => println!("Record was NOT successful"),
+    /// let params = BulkWriteParams {
+    ///     operation: BulkWriteOperation::Insert,
+    ///     module: String::from("Leads"),
+    ///     file_id,
+    ///     find_by: None,
+    ///     field_mappings,
+    /// };
+    ///
+    /// let job_id = client.create_bulk_write(&params).unwrap();
+    /// let job = client
+    ///     .wait_for_bulk_write(&job_id, Duration::from_secs(10), Duration::from_secs(3600))
+    ///     .unwrap();
+    ///
+    /// if let Some(result) = job.result {
+    ///     for row in client.download_bulk_write(&result.download_url).unwrap().rows().unwrap() {
+    ///         println!("{:?}", row.unwrap());
     ///     }
     /// }
     /// ```
-    pub fn update_many<T>(&mut self, module: &str, data: Vec<T>)-> Result<response::ApiSuccessResponse, ClientError>
-        where T: serde::ser::Serialize
-    {
-        if self.access_token.is_none() {
-            self.get_new_token()?;
-        }
+    pub fn upload_bulk_write(&mut self, org_id: &str, file: BulkWriteFile) -> Result<String, ClientError> {
+        let api_domain = self.authorized_api_domain()?;
+        let url = format!("{}/crm/v2/upload", Client::get_content_domain(&api_domain)?);
 
-        // we are guaranteed a token when we reach this line
-        let token = self.access_token().unwrap();
-        let api_domain = self.api_domain().unwrap();
+        let (file, len) = file.into_reader();
+        let part = multipart::Part::reader_with_length(file, len).file_name("records.zip");
+        let form = multipart::Form::new().part("file", part);
 
-        let timeout = Duration::from_secs(self.timeout);
-        let client = reqwest::Client::builder().timeout(timeout).build()?;
+        let request = self
+            .request_url(reqwest::Method::POST, &url)?
+            .header("feature", "bulk-write")
+            .header("X-CRM-ORG", org_id)
+            .multipart(form);
 
-        let url = format!("{}/crm/v2/{}", api_domain, module);
+        let response: BulkWriteResponse = Client::send(request)?;
 
-        // Zoho requires incoming data to be sent via a `data` field
-        let mut params: HashMap<&str, Vec<T>> = HashMap::new();
-        params.insert("data", data);
+        response.details.file_id.ok_or_else(|| ClientError::from("No file ID received"))
+    }
 
-        let mut response = client
-            .put(url.as_str())
-            .header("Authorization", String::from("Zoho-oauthtoken ") + &token)
-            .json(&params)
-            .send()?;
-        let raw_response = response.text()?;
+    /// Creates a Bulk Write job for an uploaded file, returning the ID of the job.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/bulk-write/create-job.html](https://www.zoho.com/crm/developer/docs/api/bulk-write/create-job.html)
+    pub fn create_bulk_write(&mut self, params: &BulkWriteParams) -> Result<String, ClientError> {
+        let request = self
+            .request(reqwest::Method::POST, "/crm/bulk/v2/write")?
+            .json(&BulkWriteRequest::from(params));
 
-        if let Ok(response) = serde_json::from_str::<response::ApiErrorResponse>(&raw_response) {
-            return Err(ClientError::ApiError(response));
-        }
+        let response: BulkWriteResponse = Client::send(request)?;
 
-        match serde_json::from_str::<response::ApiSuccessResponse>(&raw_response) {
-            Ok(response) => Ok(response),
-            Err(_) => {
-                if raw_response.len() > 0 {
-                    Err(ClientError::UnexpectedResponseType(raw_response))
-                } else {
-                    Err(ClientError::General(String::from("Empty response")))
-                }
-            },
-        }
+        response.details.id.ok_or_else(|| ClientError::from("No job ID received"))
     }
-}
 
-/// Utility function to help a parameter list into a URL-encoded string.
-///
-/// This should be passed into any method that supports URL-encoded parameters, such as
-/// [`get_many`](struct.Client.html#method.get_many).
-///
-/// ### Example
-///
-/// ```no_run
-/// # use serde::Deserialize;
-/// # use std::collections::HashMap;
-/// # use zoho_crm::{parse_params, Client};
-/// # #[derive(Deserialize)]
-/// # struct Record {
-/// #     id: String,
-/// # }
-/// # let mut client = Client::with_creds(None, None, String::from(""), String::from(""), String::from(""));
-/// let mut params: HashMap<&str, &str> = HashMap::new();
-/// params.insert("page", "2");
-///
-/// let params = parse_params(params).unwrap();
-/// assert_eq!("page=2", &params);
-///
-/// client.get_many::<Record>("Accounts", Some(params)).unwrap();
-/// ```
-#[allow(dead_code)]
-pub fn parse_params(params: impl serde::ser::Serialize) -> Result<String, serde_urlencoded::ser::Error> {
-    serde_urlencoded::to_string(params)
-}
+    /// Fetches the progress of a Bulk Write job.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/bulk-write/get-job-details.html](https://www.zoho.com/crm/developer/docs/api/bulk-write/get-job-details.html)
+    pub fn bulk_write_status(&mut self, job_id: &str) -> Result<BulkWriteJob, ClientError> {
+        let request = self.request(reqwest::Method::GET, &format!("/crm/bulk/v2/write/{}", job_id))?;
+
+        Client::send(request)
+    }
+
+    /// Polls the progress of a Bulk Write job every `interval`, until the job is finished.
+    ///
+    /// An error is returned if the job is still running after `timeout`.
+    pub fn wait_for_bulk_write(&mut self, job_id: &str, interval: Duration, timeout: Duration) -> Result<BulkWriteJob, ClientError> {
+        poll_until(interval, timeout, || self.bulk_write_status(job_id), BulkWriteJob::is_finished)?
+            .ok_or_else(|| ClientError::General(format!("Bulk Write job {} did not finish in time", job_id)))
+    }
+
+    /// Downloads the result file of a completed Bulk Write job, from the `download_url` of
+    /// the job.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/bulk-write/download-result.html](https://www.zoho.com/crm/developer/docs/api/bulk-write/download-result.html)
+    ///
+    /// The access token is only sent along if `download_url` points to a Zoho host. Like
+    /// [`download_bulk_read()`](#method.download_bulk_read), the file is spooled to a temporary
+    /// file rather than kept in memory.
+    pub fn download_bulk_write(&mut self, download_url: &str) -> Result<BulkWriteResult, ClientError> {
+        let request = self.request_url(reqwest::Method::GET, download_url)?;
+
+        let mut download = Client::send_download(request)?;
+        let mut file = tempfile::tempfile()?;
+        io::copy(&mut download, &mut file)?;
+        file.seek(SeekFrom::Start(0))?;
+
+        BulkWriteResult::new(file)
+    }
+
+    /// Searches a module for records matching a query.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/search-records.html](https://www.zoho.com/crm/developer/docs/api/search-records.html)
+    ///
+    /// Zoho sends back an empty response when nothing matches the query. In that case, an empty
+    /// data array is returned rather than an error.
+    ///
+    /// ### Example
+    ///
+    /// ```no_run
+    /// # use serde::Deserialize;
+    /// use zoho_crm::{Client, SearchParams, SearchQuery};
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Contact {
+    ///     id: String,
+    /// }
+    ///
+    /// # let client_id = String::from("");
+    /// # let client_secret = String::from("");
+    /// # let refresh_token = String::from("");
+    /// let mut client = Client::with_creds(None, None, client_id, client_secret, refresh_token);
+    ///
+    /// let mut params = SearchParams::new(SearchQuery::Email(String::from("burns@example.com")));
+    /// params.per_page = Some(10);
+    ///
+    /// let contacts = client.search::<Contact>("Contacts", params).unwrap();
+    /// ```
+    pub fn search<T: serde::de::DeserializeOwned>(&mut self, module: &str, params: SearchParams) -> Result<response::ApiGetManyResponse<T>, ClientError> {
+        params::validate_page(params.page, params.per_page)?;
+
+        let request = self
+            .request(reqwest::Method::GET, &format!("/crm/v2/{}/search", module))?
+            .query(&params);
+
+        Ok(Client::send_optional(request)?.unwrap_or_default())
+    }
+
+    /// Runs a COQL query and returns the matching records.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/COQL-Overview.html](https://www.zoho.com/crm/developer/docs/api/COQL-Overview.html)
+    ///
+    /// The query can be written by hand, or built with a [`CoqlQuery`](coql/struct.CoqlQuery.html).
+    /// Zoho sends back an empty response when nothing matches the query. In that case, an empty
+    /// data array is returned rather than an error.
+    ///
+    /// ### Example
+    ///
+    /// ```no_run
+    /// # use serde::Deserialize;
+    /// use zoho_crm::Client;
+    /// use zoho_crm::coql::{Condition, CoqlQuery};
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Contact {
+    ///     #[serde(rename = "Last_Name")]
+    ///     last_name: String,
+    /// }
+    ///
+    /// # let client_id = String::from("");
+    /// # let client_secret = String::from("");
+    /// # let refresh_token = String::from("");
+    /// let mut client = Client::with_creds(None, None, client_id, client_secret, refresh_token);
+    ///
+    /// let query = CoqlQuery::select(&["Last_Name"])
+    ///     .from("Contacts")
+    ///     .filter(Condition::eq("Account_Name.Account_Name", "Zylker"));
+    ///
+    /// let contacts = client.query::<Contact>(&query.to_string()).unwrap();
+    /// ```
+    pub fn query<T: serde::de::DeserializeOwned>(&mut self, query: &str) -> Result<response::ApiGetManyResponse<T>, ClientError> {
+        let mut params: HashMap<&str, &str> = HashMap::new();
+        params.insert("select_query", query);
+
+        let request = self
+            .request(reqwest::Method::POST, "/crm/v2/coql")?
+            .json(&params);
+
+        Ok(Client::send_optional(request)?.unwrap_or_default())
+    }
+
+    /// Fetches a page of records that were deleted from a module.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/get-deleted-records.html](https://www.zoho.com/crm/developer/docs/api/get-deleted-records.html)
+    ///
+    /// If no records were deleted, an empty data array is returned rather than an error.
+    ///
+    /// ### Example
+    ///
+    /// ```no_run
+    /// use zoho_crm::{Client, DeletedParams, DeletedType};
+    ///
+    /// # let client_id = String::from("");
+    /// # let client_secret = String::from("");
+    /// # let refresh_token = String::from("");
+    /// let mut client = Client::with_creds(None, None, client_id, client_secret, refresh_token);
+    ///
+    /// let params = DeletedParams::new(DeletedType::All);
+    /// let response = client.get_deleted("Accounts", params).unwrap();
+    ///
+    /// for record in response.data {
+    ///     println!("{} was deleted at {:?}", record.id, record.deleted_time);
+    /// }
+    /// ```
+    pub fn get_deleted(&mut self, module: &str, params: DeletedParams) -> Result<response::ApiGetManyResponse<response::DeletedRecord>, ClientError> {
+        params.validate()?;
+
+        let mut request = self
+            .request(reqwest::Method::GET, &format!("/crm/v2/{}/deleted", module))?
+            .query(&params);
+
+        if let Some(modified_since) = &params.modified_since {
+            request = request.header("If-Modified-Since", modified_since.as_str());
+        }
+
+        Ok(Client::send_optional(request)?.unwrap_or_default())
+    }
+
+    /// Fetches a page of records related to a record, such as the Contacts of an Account.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/get-related-records.html](https://www.zoho.com/crm/developer/docs/api/get-related-records.html)
+    ///
+    /// `related_list` is the API name of the related list, such as `Contacts` or `Products`.
+    /// If there are no related records, an empty data array is returned rather than an error.
+    ///
+    /// ### Example
+    ///
+    /// ```no_run
+    /// # use serde::Deserialize;
+    /// use zoho_crm::Client;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Contact {
+    ///     id: String,
+    /// }
+    ///
+    /// # let client_id = String::from("");
+    /// # let client_secret = String::from("");
+    /// # let refresh_token = String::from("");
+    /// let mut client = Client::with_creds(None, None, client_id, client_secret, refresh_token);
+    ///
+    /// let contacts = client.get_related::<Contact>("Accounts", "ZOHO_ID_HERE", "Contacts", None).unwrap();
+    /// ```
+    pub fn get_related<T: serde::de::DeserializeOwned>(&mut self, module: &str, id: &str, related_list: &str, params: Option<ListParams>) -> Result<response::ApiGetManyResponse<T>, ClientError> {
+        let request = self.list_request(&format!("/crm/v2/{}/{}/{}", module, id, related_list), params)?;
+
+        Ok(Client::send_optional(request)?.unwrap_or_default())
+    }
+
+    /// Relates records to a record, or updates the details of existing relations.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/update-related-records.html](https://www.zoho.com/crm/developer/docs/api/update-related-records.html)
+    ///
+    /// Each item of `data` must have the `id` of the related record. Like
+    /// [`update_many()`](struct.Client.html#method.update_many), errors for individual records
+    /// are returned alongside the record in a successful response.
+    ///
+    /// ```no_run
+    /// # use std::collections::HashMap;
+    /// # use zoho_crm::Client;
+    /// # let client_id = String::from("");
+    /// # let client_secret = String::from("");
+    /// # let refresh_token = String::from("");
+    /// # let mut zoho_client = Client::with_creds(None, None, client_id, client_secret, refresh_token);
+    /// let mut product: HashMap<&str, &str> = HashMap::new();
+    /// product.insert("id", "ZOHO_PRODUCT_ID_HERE");
+    ///
+    /// let response = zoho_client.update_related("Deals", "ZOHO_DEAL_ID_HERE", "Products", vec![product]).unwrap();
+    /// ```
+    pub fn update_related<T>(&mut self, module: &str, id: &str, related_list: &str, data: Vec<T>) -> Result<response::RelatedRecordsResponse, ClientError>
+        where T: serde::ser::Serialize
+    {
+        // Zoho requires incoming data to be sent via a `data` field
+        let mut params: HashMap<&str, Vec<T>> = HashMap::new();
+        params.insert("data", data);
+
+        let request = self
+            .request(reqwest::Method::PUT, &format!("/crm/v2/{}/{}/{}", module, id, related_list))?
+            .json(&params);
+
+        Client::send(request)
+    }
+
+    /// Removes the relation between a record and some of its related records. The related
+    /// records themselves are not deleted.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/delink-related-records.html](https://www.zoho.com/crm/developer/docs/api/delink-related-records.html)
+    ///
+    /// ```no_run
+    /// # use zoho_crm::Client;
+    /// # let client_id = String::from("");
+    /// # let client_secret = String::from("");
+    /// # let refresh_token = String::from("");
+    /// # let mut zoho_client = Client::with_creds(None, None, client_id, client_secret, refresh_token);
+    /// let response = zoho_client.delist_related("Deals", "ZOHO_DEAL_ID_HERE", "Products", &["ZOHO_PRODUCT_ID_HERE"]).unwrap();
+    /// ```
+    pub fn delist_related(&mut self, module: &str, id: &str, related_list: &str, related_ids: &[&str]) -> Result<response::RelatedRecordsResponse, ClientError> {
+        let request = self
+            .request(reqwest::Method::DELETE, &format!("/crm/v2/{}/{}/{}", module, id, related_list))?
+            .query(&[("ids", related_ids.join(","))]);
+
+        Client::send(request)
+    }
+
+    /// Insert multiple records in Zoho.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/insert-records.html](https://www.zoho.com/crm/developer/docs/api/insert-records.html)
+    ///
+    /// It is important to note that this method *may* mask errors with a successful response.
+    /// That is because record specific errors will be shown alongside the record in the response.
+    /// We do not want to assume this is an *unsuccessful* response, and so it is up to you to
+    /// handle them.
+    ///
+    /// The `params` argument accepts any serializable data type.
+    ///
+    /// ```no_run
+    /// # use std::collections::HashMap;
+    /// # use zoho_crm::Client;
+    /// # let client_id = String::from("");
+    /// # let client_secret = String::from("");
+    /// # let refresh_token = String::from("");
+    /// # let mut zoho_client = Client::with_creds(None, None, client_id, client_secret, refresh_token);
+    /// let mut record: HashMap<&str, &str> = HashMap::new();
+    /// record.insert("name", "sample");
+    ///
+    /// let response = zoho_client.insert("Accounts", vec![record]).unwrap();
+    ///
+    /// for record in response.data {
+    ///     match record.code.as_str() {
+    ///         "SUCCESS" => println!("Record was successful"),
+    ///         _ => println!("Record was NOT successful"),
+    ///     }
+    /// }
+    /// ```
+    pub fn insert<T>(&mut self, module: &str, data: Vec<T>) -> Result<response::ApiSuccessResponse, ClientError>
+        where T: serde::ser::Serialize
+    {
+        // Zoho requires incoming data to be sent via a `data` field
+        let mut params: HashMap<&str, Vec<T>> = HashMap::new();
+        params.insert("data", data);
+
+        let request = self
+            .request(reqwest::Method::POST, &format!("/crm/v2/{}", module))?
+            .json(&params);
+
+        Client::send(request)
+    }
+
+    /// Updates multiple records in Zoho.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/update-records.html](https://www.zoho.com/crm/developer/docs/api/update-records.html)
+    ///
+    /// It is important to note that this method *may* mask errors with a successful response.
+    /// That is because record specific errors will be shown alongside the record in the response.
+    /// We do not want to assume this is an *unsuccessful* response, and so it is up to you to
+    /// handle them.
+    ///
+    /// The `params` argument accepts any serializable data type.
+    ///
+    /// ```no_run
+    /// # use std::collections::HashMap;
+    /// # use zoho_crm::Client;
+    /// # let client_id = String::from("");
+    /// # let client_secret = String::from("");
+    /// # let refresh_token = String::from("");
+    /// # let mut zoho_client = Client::with_creds(None, None, client_id, client_secret, refresh_token);
+    /// let mut record: HashMap<&str, &str> = HashMap::new();
+    /// record.insert("id", "ZOHO_RECORD_ID_HERE");
+    /// record.insert("name", "sample");
+    ///
+    /// let response = zoho_client.update_many("Accounts", vec![record]).unwrap();
+    ///
+    /// for record in response.data {
+    ///     match record.code.as_str() {
+    ///         "SUCCESS" => println!("Record was successful"),
+    ///         _ => println!("Record was NOT successful"),
+    ///     }
+    /// }
+    /// ```
+    pub fn update_many<T>(&mut self, module: &str, data: Vec<T>)-> Result<response::ApiSuccessResponse, ClientError>
+        where T: serde::ser::Serialize
+    {
+        // Zoho requires incoming data to be sent via a `data` field
+        let mut params: HashMap<&str, Vec<T>> = HashMap::new();
+        params.insert("data", data);
+
+        let request = self
+            .request(reqwest::Method::PUT, &format!("/crm/v2/{}", module))?
+            .json(&params);
+
+        Client::send(request)
+    }
+
+    /// Fetches a page of notes across all records.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/get-notes.html](https://www.zoho.com/crm/developer/docs/api/get-notes.html)
+    ///
+    /// If there are no notes, an empty data array is returned rather than an error.
+    pub fn notes(&mut self, params: Option<ListParams>) -> Result<response::ApiGetManyResponse<Note>, ClientError> {
+        let request = self.list_request("/crm/v2/Notes", params)?;
+
+        Ok(Client::send_optional(request)?.unwrap_or_default())
+    }
+
+    /// Fetches a page of notes attached to a record.
+    ///
+    /// If the record has no notes, an empty data array is returned rather than an error.
+    ///
+    /// ### Example
+    ///
+    /// ```no_run
+    /// # use zoho_crm::Client;
+    /// # let client_id = String::from("");
+    /// # let client_secret = String::from("");
+    /// # let refresh_token = String::from("");
+    /// # let mut client = Client::with_creds(None, None, client_id, client_secret, refresh_token);
+    /// let response = client.record_notes("Leads", "ZOHO_ID_HERE", None).unwrap();
+    ///
+    /// for note in response.data {
+    ///     println!("{:?}: {:?}", note.note_title, note.note_content);
+    /// }
+    /// ```
+    pub fn record_notes(&mut self, module: &str, id: &str, params: Option<ListParams>) -> Result<response::ApiGetManyResponse<Note>, ClientError> {
+        let request = self.list_request(&format!("/crm/v2/{}/{}/Notes", module, id), params)?;
+
+        Ok(Client::send_optional(request)?.unwrap_or_default())
+    }
+
+    /// Creates notes on any records. Each note must have its `parent_id` and `se_module` set.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/create-notes.html](https://www.zoho.com/crm/developer/docs/api/create-notes.html)
+    ///
+    /// ```no_run
+    /// # use zoho_crm::Client;
+    /// use zoho_crm::notes::NoteData;
+    /// # let client_id = String::from("");
+    /// # let client_secret = String::from("");
+    /// # let refresh_token = String::from("");
+    /// # let mut client = Client::with_creds(None, None, client_id, client_secret, refresh_token);
+    ///
+    /// let note = NoteData {
+    ///     note_title: Some(String::from("Call summary")),
+    ///     note_content: Some(String::from("Customer asked for a quote.")),
+    ///     parent_id: Some(String::from("ZOHO_ID_HERE")),
+    ///     se_module: Some(String::from("Leads")),
+    /// };
+    ///
+    /// let response = client.create_notes(vec![note]).unwrap();
+    /// ```
+    pub fn create_notes(&mut self, notes: Vec<NoteData>) -> Result<response::ApiSuccessResponse, ClientError> {
+        let mut params: HashMap<&str, Vec<NoteData>> = HashMap::new();
+        params.insert("data", notes);
+
+        let request = self
+            .request(reqwest::Method::POST, "/crm/v2/Notes")?
+            .json(&params);
+
+        Client::send(request)
+    }
+
+    /// Creates notes on a record.
+    pub fn create_record_notes(&mut self, module: &str, id: &str, notes: Vec<NoteData>) -> Result<response::ApiSuccessResponse, ClientError> {
+        let mut params: HashMap<&str, Vec<NoteData>> = HashMap::new();
+        params.insert("data", notes);
+
+        let request = self
+            .request(reqwest::Method::POST, &format!("/crm/v2/{}/{}/Notes", module, id))?
+            .json(&params);
+
+        Client::send(request)
+    }
+
+    /// Updates a note.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/update-notes.html](https://www.zoho.com/crm/developer/docs/api/update-notes.html)
+    pub fn update_note(&mut self, note_id: &str, note: NoteData) -> Result<response::ApiSuccessResponse, ClientError> {
+        let mut params: HashMap<&str, Vec<NoteData>> = HashMap::new();
+        params.insert("data", vec![note]);
+
+        let request = self
+            .request(reqwest::Method::PUT, &format!("/crm/v2/Notes/{}", note_id))?
+            .json(&params);
+
+        Client::send(request)
+    }
+
+    /// Updates a note attached to a record.
+    pub fn update_record_note(&mut self, module: &str, id: &str, note_id: &str, note: NoteData) -> Result<response::ApiSuccessResponse, ClientError> {
+        let mut params: HashMap<&str, Vec<NoteData>> = HashMap::new();
+        params.insert("data", vec![note]);
+
+        let request = self
+            .request(reqwest::Method::PUT, &format!("/crm/v2/{}/{}/Notes/{}", module, id, note_id))?
+            .json(&params);
+
+        Client::send(request)
+    }
+
+    /// Deletes notes on any records.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/delete-notes.html](https://www.zoho.com/crm/developer/docs/api/delete-notes.html)
+    pub fn delete_notes(&mut self, note_ids: &[&str]) -> Result<response::ApiActionResponse, ClientError> {
+        let request = self
+            .request(reqwest::Method::DELETE, "/crm/v2/Notes")?
+            .query(&[("ids", note_ids.join(","))]);
+
+        Client::send(request)
+    }
+
+    /// Deletes a note attached to a record.
+    pub fn delete_record_note(&mut self, module: &str, id: &str, note_id: &str) -> Result<response::ApiActionResponse, ClientError> {
+        let request = self.request(reqwest::Method::DELETE, &format!("/crm/v2/{}/{}/Notes/{}", module, id, note_id))?;
+
+        Client::send(request)
+    }
+
+    /// Fetches a page of the files and links attached to a record.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/get-attachments.html](https://www.zoho.com/crm/developer/docs/api/get-attachments.html)
+    ///
+    /// If the record has no attachments, an empty data array is returned rather than an error.
+    pub fn list_attachments(&mut self, module: &str, id: &str, params: Option<ListParams>) -> Result<response::ApiGetManyResponse<Attachment>, ClientError> {
+        let request = self.list_request(&format!("/crm/v2/{}/{}/Attachments", module, id), params)?;
+
+        Ok(Client::send_optional(request)?.unwrap_or_default())
+    }
+
+    /// Uploads a file and attaches it to a record.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/upload-attachment.html](https://www.zoho.com/crm/developer/docs/api/upload-attachment.html)
+    ///
+    /// The file is streamed from `reader` as it is uploaded, so it is never held in memory all
+    /// at once.
+    ///
+    /// ```no_run
+    /// # use zoho_crm::Client;
+    /// # let client_id = String::from("");
+    /// # let client_secret = String::from("");
+    /// # let refresh_token = String::from("");
+    /// # let mut client = Client::with_creds(None, None, client_id, client_secret, refresh_token);
+    /// let file = std::fs::File::open("quote.pdf").unwrap();
+    ///
+    /// let response = client.upload_attachment("Deals", "ZOHO_ID_HERE", file, "quote.pdf").unwrap();
+    /// ```
+    pub fn upload_attachment<R>(&mut self, module: &str, id: &str, reader: R, filename: &str) -> Result<response::ApiSuccessResponse, ClientError>
+        where R: Read + Send + 'static
+    {
+        let part = multipart::Part::reader(reader).file_name(filename.to_string());
+
+        self.multipart_upload(&format!("/crm/v2/{}/{}/Attachments", module, id), part)
+    }
+
+    /// Attaches a link to a record.
+    pub fn attach_url(&mut self, module: &str, id: &str, url: &str) -> Result<response::ApiSuccessResponse, ClientError> {
+        let form = multipart::Form::new().text("attachmentUrl", url.to_string());
+
+        let request = self
+            .request(reqwest::Method::POST, &format!("/crm/v2/{}/{}/Attachments", module, id))?
+            .multipart(form);
+
+        Client::send(request)
+    }
+
+    /// Downloads a file attached to a record.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/download-attachments.html](https://www.zoho.com/crm/developer/docs/api/download-attachments.html)
+    ///
+    /// The returned [`Download`](attachments/struct.Download.html) streams the file as it is
+    /// read.
+    pub fn download_attachment(&mut self, module: &str, id: &str, attachment_id: &str) -> Result<Download, ClientError> {
+        let request = self.request(reqwest::Method::GET, &format!("/crm/v2/{}/{}/Attachments/{}", module, id, attachment_id))?;
+
+        Client::send_download(request)
+    }
+
+    /// Deletes a file or link attached to a record.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/delete-attachments.html](https://www.zoho.com/crm/developer/docs/api/delete-attachments.html)
+    pub fn delete_attachment(&mut self, module: &str, id: &str, attachment_id: &str) -> Result<response::ApiActionResponse, ClientError> {
+        let request = self.request(reqwest::Method::DELETE, &format!("/crm/v2/{}/{}/Attachments/{}", module, id, attachment_id))?;
+
+        Client::send(request)
+    }
+
+    /// Downloads the image of a record, such as a Contact, Lead or Account.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/download-record-photo.html](https://www.zoho.com/crm/developer/docs/api/download-record-photo.html)
+    ///
+    /// ```no_run
+    /// # use zoho_crm::Client;
+    /// # let client_id = String::from("");
+    /// # let client_secret = String::from("");
+    /// # let refresh_token = String::from("");
+    /// # let mut client = Client::with_creds(None, None, client_id, client_secret, refresh_token);
+    /// let photo = client.get_photo("Contacts", "ZOHO_ID_HERE").unwrap();
+    ///
+    /// println!("{:?} image of {} bytes", photo.content_type, photo.data.len());
+    /// ```
+    pub fn get_photo(&mut self, module: &str, id: &str) -> Result<Photo, ClientError> {
+        let request = self.request(reqwest::Method::GET, &format!("/crm/v2/{}/{}/photo", module, id))?;
+        let mut download = Client::send_download(request)?;
+
+        let mut data = Vec::new();
+        download.read_to_end(&mut data)?;
+
+        Ok(Photo {
+            content_type: download.content_type().map(|content_type| content_type.to_string()),
+            data,
+        })
+    }
+
+    /// Uploads the image of a record, replacing any existing image.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/upload-record-photo.html](https://www.zoho.com/crm/developer/docs/api/upload-record-photo.html)
+    pub fn upload_photo<R>(&mut self, module: &str, id: &str, reader: R, filename: &str) -> Result<response::ApiStatusResponse, ClientError>
+        where R: Read + Send + 'static
+    {
+        let part = multipart::Part::reader(reader).file_name(filename.to_string());
+
+        self.multipart_upload(&format!("/crm/v2/{}/{}/photo", module, id), part)
+    }
+
+    /// Deletes the image of a record.
+    pub fn delete_photo(&mut self, module: &str, id: &str) -> Result<response::ApiStatusResponse, ClientError> {
+        let request = self.request(reqwest::Method::DELETE, &format!("/crm/v2/{}/{}/photo", module, id))?;
+
+        Client::send(request)
+    }
+
+    /// Converts a lead into a contact, and an account and deal if requested.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/convert-lead.html](https://www.zoho.com/crm/developer/docs/api/convert-lead.html)
+    ///
+    /// ### Example
+    ///
+    /// ```no_run
+    /// # use zoho_crm::Client;
+    /// use zoho_crm::leads::ConvertLeadOptions;
+    /// # let client_id = String::from("");
+    /// # let client_secret = String::from("");
+    /// # let refresh_token = String::from("");
+    /// # let mut client = Client::with_creds(None, None, client_id, client_secret, refresh_token);
+    ///
+    /// let options = ConvertLeadOptions {
+    ///     overwrite: Some(true),
+    ///     ..ConvertLeadOptions::default()
+    /// };
+    ///
+    /// let converted = client.convert_lead("ZOHO_LEAD_ID_HERE", options).unwrap();
+    /// println!("New contact: {:?}", converted.contact_id);
+    /// ```
+    pub fn convert_lead(&mut self, lead_id: &str, options: ConvertLeadOptions) -> Result<ConvertedLead, ClientError> {
+        let mut params: HashMap<&str, Vec<ConvertLeadOptions>> = HashMap::new();
+        params.insert("data", vec![options]);
+
+        let request = self
+            .request(reqwest::Method::POST, &format!("/crm/v2/Leads/{}/actions/convert", lead_id))?
+            .json(&params);
+
+        let response: response::ApiGetResponse<ConvertLeadResponseItem> = Client::send(request)?;
+
+        match response.data.into_iter().next() {
+            Some(item) if item.status.as_deref() == Some("error") => Err(ClientError::ApiError(response::ApiErrorResponse {
+                code: item.code.unwrap_or_default(),
+                message: item.message.unwrap_or_default(),
+                status: String::from("error"),
+            })),
+            Some(item) => Ok(item.converted),
+            None => Err(ClientError::from("No converted records received")),
+        }
+    }
+
+    /// Fetches the tags of a module.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/get-tag-list.html](https://www.zoho.com/crm/developer/docs/api/get-tag-list.html)
+    ///
+    /// If the module has no tags, an empty list is returned rather than an error.
+    pub fn tags(&mut self, module: &str) -> Result<TagsResponse, ClientError> {
+        let request = self
+            .request(reqwest::Method::GET, "/crm/v2/settings/tags")?
+            .query(&[("module", module)]);
+
+        Ok(Client::send_optional(request)?.unwrap_or_default())
+    }
+
+    /// Creates tags for a module.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/create-tags.html](https://www.zoho.com/crm/developer/docs/api/create-tags.html)
+    ///
+    /// ```no_run
+    /// # use zoho_crm::Client;
+    /// # let client_id = String::from("");
+    /// # let client_secret = String::from("");
+    /// # let refresh_token = String::from("");
+    /// # let mut client = Client::with_creds(None, None, client_id, client_secret, refresh_token);
+    /// let response = client.create_tags("Leads", &["Newsletter", "Webinar"]).unwrap();
+    ///
+    /// for tag in response.tags {
+    ///     println!("{}: {:?}", tag.code, tag.details.id);
+    /// }
+    /// ```
+    pub fn create_tags(&mut self, module: &str, names: &[&str]) -> Result<TagActionResponse, ClientError> {
+        let tags: Vec<HashMap<&str, &str>> = names
+            .iter()
+            .map(|name| {
+                let mut tag = HashMap::new();
+                tag.insert("name", *name);
+                tag
+            })
+            .collect();
+
+        let mut params: HashMap<&str, Vec<HashMap<&str, &str>>> = HashMap::new();
+        params.insert("tags", tags);
+
+        let request = self
+            .request(reqwest::Method::POST, "/crm/v2/settings/tags")?
+            .query(&[("module", module)])
+            .json(&params);
+
+        Client::send(request)
+    }
+
+    /// Renames a tag of a module.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/update-tag.html](https://www.zoho.com/crm/developer/docs/api/update-tag.html)
+    pub fn update_tag(&mut self, module: &str, tag_id: &str, name: &str) -> Result<TagActionResponse, ClientError> {
+        let mut tag: HashMap<&str, &str> = HashMap::new();
+        tag.insert("name", name);
+
+        let mut params: HashMap<&str, Vec<HashMap<&str, &str>>> = HashMap::new();
+        params.insert("tags", vec![tag]);
+
+        let request = self
+            .request(reqwest::Method::PUT, &format!("/crm/v2/settings/tags/{}", tag_id))?
+            .query(&[("module", module)])
+            .json(&params);
+
+        Client::send(request)
+    }
+
+    /// Merges a tag into another tag. Records with the tag `tag_id` are given the tag
+    /// `conflict_id`, and `tag_id` is deleted.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/merge-tags.html](https://www.zoho.com/crm/developer/docs/api/merge-tags.html)
+    pub fn merge_tags(&mut self, tag_id: &str, conflict_id: &str) -> Result<TagActionResponse, ClientError> {
+        let mut tag: HashMap<&str, &str> = HashMap::new();
+        tag.insert("conflict_id", conflict_id);
+
+        let mut params: HashMap<&str, Vec<HashMap<&str, &str>>> = HashMap::new();
+        params.insert("tags", vec![tag]);
+
+        let request = self
+            .request(reqwest::Method::POST, &format!("/crm/v2/settings/tags/{}/actions/merge", tag_id))?
+            .json(&params);
+
+        Client::send(request)
+    }
+
+    /// Deletes a tag, removing it from any records that have it.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/delete-tag.html](https://www.zoho.com/crm/developer/docs/api/delete-tag.html)
+    pub fn delete_tag(&mut self, tag_id: &str) -> Result<TagActionResponse, ClientError> {
+        let request = self.request(reqwest::Method::DELETE, &format!("/crm/v2/settings/tags/{}", tag_id))?;
+
+        Client::send(request)
+    }
+
+    /// Adds tags to a record. Tags that do not exist yet are created.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/add-tags.html](https://www.zoho.com/crm/developer/docs/api/add-tags.html)
+    ///
+    /// When `over_write` is `true`, any tags the record already has are removed.
+    pub fn add_tags(&mut self, module: &str, id: &str, names: &[&str], over_write: bool) -> Result<RecordTagsResponse, ClientError> {
+        let request = self
+            .request(reqwest::Method::POST, &format!("/crm/v2/{}/{}/actions/add_tags", module, id))?
+            .query(&[("tag_names", names.join(",")), ("over_write", over_write.to_string())]);
+
+        Client::send(request)
+    }
+
+    /// Removes tags from a record.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/remove-tags.html](https://www.zoho.com/crm/developer/docs/api/remove-tags.html)
+    pub fn remove_tags(&mut self, module: &str, id: &str, names: &[&str]) -> Result<RecordTagsResponse, ClientError> {
+        let request = self
+            .request(reqwest::Method::POST, &format!("/crm/v2/{}/{}/actions/remove_tags", module, id))?
+            .query(&[("tag_names", names.join(","))]);
+
+        Client::send(request)
+    }
+
+    /// Adds tags to multiple records at once. Tags that do not exist yet are created.
+    ///
+    /// When `over_write` is `true`, any tags the records already have are removed.
+    ///
+    /// ```no_run
+    /// # use zoho_crm::Client;
+    /// # let client_id = String::from("");
+    /// # let client_secret = String::from("");
+    /// # let refresh_token = String::from("");
+    /// # let mut client = Client::with_creds(None, None, client_id, client_secret, refresh_token);
+    /// let ids = ["ZOHO_ID_1", "ZOHO_ID_2"];
+    ///
+    /// let response = client.add_tags_to_records("Leads", &ids, &["Newsletter"], false).unwrap();
+    /// ```
+    pub fn add_tags_to_records(&mut self, module: &str, ids: &[&str], names: &[&str], over_write: bool) -> Result<RecordTagsResponse, ClientError> {
+        let request = self
+            .request(reqwest::Method::POST, &format!("/crm/v2/{}/actions/add_tags", module))?
+            .query(&[("ids", ids.join(",")), ("tag_names", names.join(",")), ("over_write", over_write.to_string())]);
+
+        Client::send(request)
+    }
+
+    /// Removes tags from multiple records at once.
+    pub fn remove_tags_from_records(&mut self, module: &str, ids: &[&str], names: &[&str]) -> Result<RecordTagsResponse, ClientError> {
+        let request = self
+            .request(reqwest::Method::POST, &format!("/crm/v2/{}/actions/remove_tags", module))?
+            .query(&[("ids", ids.join(",")), ("tag_names", names.join(","))]);
+
+        Client::send(request)
+    }
+
+    /// Starts a job that sets the same field values on many records at once. The ID of the
+    /// job is returned, which can be passed to the
+    /// [`mass_update_status()`](#method.mass_update_status) method.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/mass-update-records.html](https://www.zoho.com/crm/developer/docs/api/mass-update-records.html)
+    ///
+    /// ### Example
+    ///
+    /// ```no_run
+    /// # use zoho_crm::Client;
+    /// use std::collections::HashMap;
+    /// use std::time::Duration;
+    /// use zoho_crm::mass_update::MassUpdateTarget;
+    /// # let client_id = String::from("");
+    /// # let client_secret = String::from("");
+    /// # let refresh_token = String::from("");
+    /// # let mut client = Client::with_creds(None, None, client_id, client_secret, refresh_token);
+    ///
+    /// let mut values = HashMap::new();
+    /// values.insert("Lead_Status", "Contacted");
+    ///
+    /// let target = MassUpdateTarget::Cvid(String::from("YOUR_VIEW_ID_HERE"));
+    /// let job_id = client.mass_update("Leads", target, values).unwrap();
+    ///
+    /// let status = client
+    ///     .wait_for_mass_update("Leads", &job_id, Duration::from_secs(5), Duration::from_secs(600))
+    ///     .unwrap();
+    /// println!("Updated {} of {} records", status.updated_count, status.total_count);
+    /// ```
+    pub fn mass_update<T>(&mut self, module: &str, target: MassUpdateTarget, values: T) -> Result<String, ClientError>
+        where T: serde::ser::Serialize
+    {
+        let params = MassUpdateRequest {
+            data: vec![values],
+            target,
+        };
+
+        let request = self
+            .request(reqwest::Method::POST, &format!("/crm/v2/{}/actions/mass_update", module))?
+            .json(&params);
+
+        let response: MassUpdateResponse = Client::send(request)?;
+
+        match response.data.into_iter().next() {
+            Some(item) if item.status == "error" => Err(ClientError::ApiError(response::ApiErrorResponse {
+                code: item.code,
+                message: item.message,
+                status: item.status,
+            })),
+            Some(item) => item.details.job_id.ok_or_else(|| ClientError::from("No job ID received")),
+            None => Err(ClientError::from("No job ID received")),
+        }
+    }
+
+    /// Fetches the progress of a mass update job.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/get-mass-update-status.html](https://www.zoho.com/crm/developer/docs/api/get-mass-update-status.html)
+    pub fn mass_update_status(&mut self, module: &str, job_id: &str) -> Result<MassUpdateStatus, ClientError> {
+        let request = self
+            .request(reqwest::Method::GET, &format!("/crm/v2/{}/actions/mass_update", module))?
+            .query(&[("job_id", job_id)]);
+
+        let response: response::ApiGetResponse<MassUpdateStatus> = Client::send(request)?;
+
+        match response.data.into_iter().next() {
+            Some(status) => Ok(status),
+            None => Err(ClientError::from("No job status received")),
+        }
+    }
+
+    /// Polls the progress of a mass update job every `interval`, until the job is finished.
+    ///
+    /// An error is returned if the job is still running after `timeout`.
+    pub fn wait_for_mass_update(&mut self, module: &str, job_id: &str, interval: Duration, timeout: Duration) -> Result<MassUpdateStatus, ClientError> {
+        poll_until(interval, timeout, || self.mass_update_status(module, job_id), MassUpdateStatus::is_finished)?
+            .ok_or_else(|| ClientError::General(format!("Mass update job {} did not finish in time", job_id)))
+    }
+
+    /// Fetches the modules of your Zoho account, including custom modules.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/module-meta.html](https://www.zoho.com/crm/developer/docs/api/module-meta.html)
+    ///
+    /// ### Example
+    ///
+    /// ```no_run
+    /// # use zoho_crm::Client;
+    /// # let client_id = String::from("");
+    /// # let client_secret = String::from("");
+    /// # let refresh_token = String::from("");
+    /// # let mut client = Client::with_creds(None, None, client_id, client_secret, refresh_token);
+    /// let modules = client.modules().unwrap();
+    ///
+    /// for module in modules.iter().filter(|module| module.api_supported) {
+    ///     println!("{}: {}", module.api_name, module.plural_label);
+    /// }
+    /// ```
+    pub fn modules(&mut self) -> Result<Vec<ModuleMeta>, ClientError> {
+        let request = self.request(reqwest::Method::GET, "/crm/v2/settings/modules")?;
+
+        let response: ModulesResponse = Client::send(request)?;
+
+        Ok(response.modules)
+    }
+
+    /// Fetches a single module by its API name.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/modules-api.html](https://www.zoho.com/crm/developer/docs/api/modules-api.html)
+    pub fn module(&mut self, api_name: &str) -> Result<ModuleMeta, ClientError> {
+        let request = self.request(reqwest::Method::GET, &format!("/crm/v2/settings/modules/{}", api_name))?;
+
+        let response: ModulesResponse = Client::send(request)?;
+
+        match response.modules.into_iter().next() {
+            Some(module) => Ok(module),
+            None => Err(ClientError::from("No module received")),
+        }
+    }
+
+    /// Fetches the fields of a module.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/field-meta.html](https://www.zoho.com/crm/developer/docs/api/field-meta.html)
+    ///
+    /// ### Example
+    ///
+    /// ```no_run
+    /// # use zoho_crm::Client;
+    /// use zoho_crm::metadata::DataType;
+    /// # let client_id = String::from("");
+    /// # let client_secret = String::from("");
+    /// # let refresh_token = String::from("");
+    /// # let mut client = Client::with_creds(None, None, client_id, client_secret, refresh_token);
+    ///
+    /// let fields = client.fields("Leads").unwrap();
+    ///
+    /// for field in fields.iter().filter(|field| field.data_type == DataType::Picklist) {
+    ///     println!("{}: {} values", field.api_name, field.pick_list_values.len());
+    /// }
+    /// ```
+    pub fn fields(&mut self, module: &str) -> Result<Vec<FieldMeta>, ClientError> {
+        let request = self
+            .request(reqwest::Method::GET, "/crm/v2/settings/fields")?
+            .query(&[("module", module)]);
+
+        let response: FieldsResponse = Client::send(request)?;
+
+        Ok(response.fields)
+    }
+
+    /// Fetches the layouts of a module, with their sections and fields.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/layouts-meta.html](https://www.zoho.com/crm/developer/docs/api/layouts-meta.html)
+    pub fn layouts(&mut self, module: &str) -> Result<Vec<Layout>, ClientError> {
+        let request = self
+            .request(reqwest::Method::GET, "/crm/v2/settings/layouts")?
+            .query(&[("module", module)]);
+
+        let response: LayoutsResponse = Client::send(request)?;
+
+        Ok(response.layouts)
+    }
+
+    /// Fetches the custom views of a module. The criteria and fields of each view are only
+    /// sent by the [`custom_view()`](#method.custom_view) method.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/custom-view-meta.html](https://www.zoho.com/crm/developer/docs/api/custom-view-meta.html)
+    ///
+    /// ### Example
+    ///
+    /// ```no_run
+    /// # use serde::Deserialize;
+    /// # use zoho_crm::Client;
+    /// use zoho_crm::ListParams;
+    /// # let client_id = String::from("");
+    /// # let client_secret = String::from("");
+    /// # let refresh_token = String::from("");
+    /// # let mut client = Client::with_creds(None, None, client_id, client_secret, refresh_token);
+    /// # #[derive(Debug, Deserialize)]
+    /// # struct Lead {}
+    ///
+    /// let views = client.custom_views("Leads").unwrap();
+    /// let view = views.into_iter().find(|view| view.display_value == "Open Leads").unwrap();
+    ///
+    /// let params = ListParams {
+    ///     cvid: Some(view.id),
+    ///     ..ListParams::default()
+    /// };
+    ///
+    /// let leads = client.get_many::<Lead>("Leads", Some(params)).unwrap();
+    /// ```
+    pub fn custom_views(&mut self, module: &str) -> Result<Vec<CustomView>, ClientError> {
+        let request = self
+            .request(reqwest::Method::GET, "/crm/v2/settings/custom_views")?
+            .query(&[("module", module)]);
+
+        let response: CustomViewsResponse = Client::send(request)?;
+
+        Ok(response.custom_views)
+    }
+
+    /// Fetches a single custom view of a module, including its criteria and fields.
+    pub fn custom_view(&mut self, module: &str, id: &str) -> Result<CustomView, ClientError> {
+        let request = self
+            .request(reqwest::Method::GET, &format!("/crm/v2/settings/custom_views/{}", id))?
+            .query(&[("module", module)]);
+
+        let response: CustomViewsResponse = Client::send(request)?;
+
+        match response.custom_views.into_iter().next() {
+            Some(view) => Ok(view),
+            None => Err(ClientError::from("No custom view received")),
+        }
+    }
+
+    /// Fetches a page of users of your organization.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/get-users.html](https://www.zoho.com/crm/developer/docs/api/get-users.html)
+    ///
+    /// ### Example
+    ///
+    /// ```no_run
+    /// # use zoho_crm::Client;
+    /// use zoho_crm::users::{UserType, UsersParams};
+    /// # let client_id = String::from("");
+    /// # let client_secret = String::from("");
+    /// # let refresh_token = String::from("");
+    /// # let mut client = Client::with_creds(None, None, client_id, client_secret, refresh_token);
+    ///
+    /// let users = client.users(UsersParams::new(UserType::ActiveUsers)).unwrap();
+    ///
+    /// for user in users.data {
+    ///     println!("{}: {:?}", user.id, user.email);
+    /// }
+    /// ```
+    pub fn users(&mut self, params: UsersParams) -> Result<response::ApiGetManyResponse<User>, ClientError> {
+        params.validate()?;
+
+        let request = self
+            .request(reqwest::Method::GET, "/crm/v2/users")?
+            .query(&params);
+
+        let response: UsersResponse = Client::send_optional(request)?.unwrap_or_default();
+
+        Ok(response.into())
+    }
+
+    /// Fetches a single user.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/get-users.html](https://www.zoho.com/crm/developer/docs/api/get-users.html)
+    pub fn user(&mut self, id: &str) -> Result<User, ClientError> {
+        let request = self.request(reqwest::Method::GET, &format!("/crm/v2/users/{}", id))?;
+
+        let response: UsersResponse = Client::send(request)?;
+
+        match response.users.into_iter().next() {
+            Some(user) => Ok(user),
+            None => Err(ClientError::from("No user received")),
+        }
+    }
+
+    /// Invites a new user to your organization.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/post-users.html](https://www.zoho.com/crm/developer/docs/api/post-users.html)
+    ///
+    /// ### Example
+    ///
+    /// ```no_run
+    /// # use zoho_crm::Client;
+    /// use zoho_crm::users::UserData;
+    /// # let client_id = String::from("");
+    /// # let client_secret = String::from("");
+    /// # let refresh_token = String::from("");
+    /// # let mut client = Client::with_creds(None, None, client_id, client_secret, refresh_token);
+    ///
+    /// let user = UserData {
+    ///     last_name: Some(String::from("Smithers")),
+    ///     email: Some(String::from("smithers@example.com")),
+    ///     role: Some(String::from("ROLE_ID_HERE")),
+    ///     profile: Some(String::from("PROFILE_ID_HERE")),
+    ///     ..UserData::default()
+    /// };
+    ///
+    /// let response = client.create_user(user).unwrap();
+    /// ```
+    pub fn create_user(&mut self, user: UserData) -> Result<UserActionResponse, ClientError> {
+        let mut params: HashMap<&str, Vec<UserData>> = HashMap::new();
+        params.insert("users", vec![user]);
+
+        let request = self
+            .request(reqwest::Method::POST, "/crm/v2/users")?
+            .json(&params);
+
+        Client::send(request)
+    }
+
+    /// Updates a user.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/update-user.html](https://www.zoho.com/crm/developer/docs/api/update-user.html)
+    pub fn update_user(&mut self, id: &str, user: UserData) -> Result<UserActionResponse, ClientError> {
+        let mut params: HashMap<&str, Vec<UserData>> = HashMap::new();
+        params.insert("users", vec![user]);
+
+        let request = self
+            .request(reqwest::Method::PUT, &format!("/crm/v2/users/{}", id))?
+            .json(&params);
+
+        Client::send(request)
+    }
+
+    /// Deletes a user.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/delete-user.html](https://www.zoho.com/crm/developer/docs/api/delete-user.html)
+    pub fn delete_user(&mut self, id: &str) -> Result<response::ApiStatusResponse, ClientError> {
+        let request = self.request(reqwest::Method::DELETE, &format!("/crm/v2/users/{}", id))?;
+
+        let response: UserActionResponse = Client::send(request)?;
+
+        match response.users.into_iter().next() {
+            Some(item) => Ok(response::ApiStatusResponse {
+                code: item.code,
+                message: item.message,
+                status: item.status,
+            }),
+            None => Err(ClientError::from("No status received")),
+        }
+    }
+
+    /// Fetches the details of your organization, such as its time zone and home currency.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/get-org-data.html](https://www.zoho.com/crm/developer/docs/api/get-org-data.html)
+    ///
+    /// ### Example
+    ///
+    /// ```no_run
+    /// # use zoho_crm::Client;
+    /// # let client_id = String::from("");
+    /// # let client_secret = String::from("");
+    /// # let refresh_token = String::from("");
+    /// # let mut client = Client::with_creds(None, None, client_id, client_secret, refresh_token);
+    /// let org = client.org().unwrap();
+    ///
+    /// println!("{:?} ({:?})", org.iso_code, org.time_zone);
+    /// ```
+    pub fn org(&mut self) -> Result<Org, ClientError> {
+        let request = self.request(reqwest::Method::GET, "/crm/v2/org")?;
+
+        let response: OrgResponse = Client::send(request)?;
+
+        match response.org.into_iter().next() {
+            Some(org) => Ok(org),
+            None => Err(ClientError::from("No organization received")),
+        }
+    }
+
+    /// Fetches the roles of your organization.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/get-roles.html](https://www.zoho.com/crm/developer/docs/api/get-roles.html)
+    pub fn roles(&mut self) -> Result<Vec<Role>, ClientError> {
+        let request = self.request(reqwest::Method::GET, "/crm/v2/settings/roles")?;
+
+        let response: RolesResponse = Client::send(request)?;
+
+        Ok(response.roles)
+    }
+
+    /// Fetches the profiles of your organization.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/get-profiles.html](https://www.zoho.com/crm/developer/docs/api/get-profiles.html)
+    pub fn profiles(&mut self) -> Result<Vec<Profile>, ClientError> {
+        let request = self.request(reqwest::Method::GET, "/crm/v2/settings/profiles")?;
+
+        let response: ProfilesResponse = Client::send(request)?;
+
+        Ok(response.profiles)
+    }
+
+    /// Fetches the territories of your organization. An empty list is returned when
+    /// territory management is not enabled.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/get-territories.html](https://www.zoho.com/crm/developer/docs/api/get-territories.html)
+    pub fn territories(&mut self) -> Result<Vec<Territory>, ClientError> {
+        let request = self.request(reqwest::Method::GET, "/crm/v2/settings/territories")?;
+
+        let response: TerritoriesResponse = Client::send_optional(request)?.unwrap_or_default();
+
+        Ok(response.territories)
+    }
+
+    /// Fetches the currencies enabled for your organization.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/get-currencies.html](https://www.zoho.com/crm/developer/docs/api/get-currencies.html)
+    pub fn currencies(&mut self) -> Result<Vec<OrgCurrency>, ClientError> {
+        let request = self.request(reqwest::Method::GET, "/crm/v2/org/currencies")?;
+
+        let response: CurrenciesResponse<OrgCurrency> = Client::send(request)?;
+
+        Ok(response.currencies)
+    }
+
+    /// Adds currencies to your organization. Multiple currencies must be enabled first, with
+    /// the [`enable_multi_currency()`](#method.enable_multi_currency) method.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/add-currencies.html](https://www.zoho.com/crm/developer/docs/api/add-currencies.html)
+    ///
+    /// ### Example
+    ///
+    /// ```no_run
+    /// # use zoho_crm::Client;
+    /// use zoho_crm::currency::CurrencyData;
+    /// # let client_id = String::from("");
+    /// # let client_secret = String::from("");
+    /// # let refresh_token = String::from("");
+    /// # let mut client = Client::with_creds(None, None, client_id, client_secret, refresh_token);
+    ///
+    /// let euro = CurrencyData {
+    ///     name: Some(String::from("Euro - EUR")),
+    ///     iso_code: Some(String::from("EUR")),
+    ///     symbol: Some(String::from("€")),
+    ///     exchange_rate: Some("0.850000000".parse().unwrap()),
+    ///     ..CurrencyData::default()
+    /// };
+    ///
+    /// let response = client.add_currencies(vec![euro]).unwrap();
+    /// ```
+    pub fn add_currencies(&mut self, currencies: Vec<CurrencyData>) -> Result<response::ApiActionResponse, ClientError> {
+        let mut params: HashMap<&str, Vec<CurrencyData>> = HashMap::new();
+        params.insert("currencies", currencies);
+
+        let request = self
+            .request(reqwest::Method::POST, "/crm/v2/org/currencies")?
+            .json(&params);
+
+        let response: CurrenciesResponse<response::ApiActionResponseItem> = Client::send(request)?;
+
+        Ok(response::ApiActionResponse { data: response.currencies })
+    }
+
+    /// Updates currencies of your organization, such as their exchange rates. The `id` of
+    /// each currency must be set.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/update-currencies.html](https://www.zoho.com/crm/developer/docs/api/update-currencies.html)
+    pub fn update_currencies(&mut self, currencies: Vec<CurrencyData>) -> Result<response::ApiActionResponse, ClientError> {
+        let mut params: HashMap<&str, Vec<CurrencyData>> = HashMap::new();
+        params.insert("currencies", currencies);
+
+        let request = self
+            .request(reqwest::Method::PUT, "/crm/v2/org/currencies")?
+            .json(&params);
+
+        let response: CurrenciesResponse<response::ApiActionResponseItem> = Client::send(request)?;
+
+        Ok(response::ApiActionResponse { data: response.currencies })
+    }
+
+    /// Enables multiple currencies for your organization, with `base_currency` as the home
+    /// currency. This cannot be undone.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/enable-multi-currency.html](https://www.zoho.com/crm/developer/docs/api/enable-multi-currency.html)
+    pub fn enable_multi_currency(&mut self, base_currency: CurrencyData) -> Result<response::ApiActionResponse, ClientError> {
+        let mut params: HashMap<&str, CurrencyData> = HashMap::new();
+        params.insert("base_currency", base_currency);
+
+        let request = self
+            .request(reqwest::Method::POST, "/crm/v2/org/currencies/actions/enable")?
+            .json(&params);
+
+        let response: BaseCurrencyResponse = Client::send(request)?;
+
+        Ok(response::ApiActionResponse { data: vec![response.base_currency] })
+    }
+
+    /// Counts the records of a module. Every record is counted when `filter` is `None`.
+    ///
+    /// Zoho API function documentation:
+    /// [https://www.zoho.com/crm/developer/docs/api/get-record-count.html](https://www.zoho.com/crm/developer/docs/api/get-record-count.html)
+    ///
+    /// ### Example
+    ///
+    /// ```no_run
+    /// # use zoho_crm::Client;
+    /// use zoho_crm::Criteria;
+    /// # let client_id = String::from("");
+    /// # let client_secret = String::from("");
+    /// # let refresh_token = String::from("");
+    /// # let mut client = Client::with_creds(None, None, client_id, client_secret, refresh_token);
+    ///
+    /// let criteria = Criteria::equals("Lead_Source", "Web");
+    /// let count = client.count("Leads", Some(criteria.into())).unwrap();
+    ///
+    /// println!("{} leads from the web", count);
+    /// ```
+    pub fn count(&mut self, module: &str, filter: Option<CountFilter>) -> Result<usize, ClientError> {
+        let request = self.request(reqwest::Method::GET, &format!("/crm/v2/{}/actions/count", module))?;
+
+        let request = match filter {
+            Some(CountFilter::Search(query)) => request.query(&SearchParams::new(query)),
+            Some(CountFilter::Cvid(cvid)) => request.query(&[("cvid", cvid)]),
+            None => request,
+        };
+
+        let response: response::CountResponse = Client::send_optional(request)?.unwrap_or_default();
+
+        Ok(response.count)
+    }
+}
+
+/// Zoho data centers, by the top-level domain of their hosts.
+const ZOHO_TLDS: &[&str] = &["com", "eu", "in", "com.au", "com.cn", "jp", "ca", "sa", "uk"];
+
+/// Whether `host` belongs to Zoho, such as `www.zohoapis.com` or `download-accl.zoho.eu`.
+fn is_zoho_host(host: &str) -> bool {
+    let host = host.to_ascii_lowercase();
+
+    ["zoho", "zohoapis"].iter().any(|name| {
+        ZOHO_TLDS.iter().any(|tld| {
+            let domain = format!("{}.{}", name, tld);
+
+            host == domain || host.ends_with(&format!(".{}", domain))
+        })
+    })
+}
+
+/// Get the domain Bulk Write files are uploaded to, such as `https://content.zohoapis.com` for
+/// the `https://www.zohoapis.com` API domain.
+fn content_domain(api_domain: &str) -> Result<String, ClientError> {
+    let mut url = reqwest::Url::parse(api_domain)?;
+    let host = url.host_str().unwrap_or_default().to_ascii_lowercase();
+
+    let content_host = match host.split('.').collect::<Vec<_>>().as_slice() {
+        ["www", "zohoapis", ..] | ["zohoapis", ..] if is_zoho_host(&host) => {
+            format!("content.{}", host.trim_start_matches("www."))
+        },
+        ["crmsandbox", ..] if is_zoho_host(&host) => {
+            return Err(ClientError::General(String::from("Bulk Write files cannot be uploaded to the sandbox")));
+        },
+        _ => return Err(ClientError::General(format!("{} is not a Zoho API domain", api_domain))),
+    };
+
+    url.set_host(Some(&content_host))?;
+
+    Ok(url.as_str().trim_end_matches('/').to_string())
+}
+
+/// Call `fetch` every `interval` until `is_finished` is true of its result.
+///
+/// `None` is returned if the result is still not finished after `timeout`.
+fn poll_until<T, F, P>(interval: Duration, timeout: Duration, mut fetch: F, is_finished: P) -> Result<Option<T>, ClientError>
+    where F: FnMut() -> Result<T, ClientError>,
+          P: Fn(&T) -> bool
+{
+    let started = Instant::now();
+
+    loop {
+        let result = fetch()?;
+
+        if is_finished(&result) {
+            return Ok(Some(result));
+        }
+
+        if started.elapsed() + interval > timeout {
+            return Ok(None);
+        }
+
+        thread::sleep(interval);
+    }
+}
+
+/// Parse a raw response body into `T`.
+///
+/// If the body is an error response from Zoho, a
+/// [`ClientError::ApiError`](enum.ClientError.html#variant.ApiError) is returned. If the body
+/// does not match `T`, the raw body is returned with a
+/// [`ClientError::UnexpectedResponseType`](enum.ClientError.html#variant.UnexpectedResponseType).
+pub(crate) fn parse_response<T: serde::de::DeserializeOwned>(raw_response: &str) -> Result<T, ClientError> {
+    if let Ok(response) = serde_json::from_str::<response::ApiErrorResponse>(raw_response) {
+        if response.status == "error" {
+            return Err(ClientError::ApiError(response));
+        }
+    }
+
+    match serde_json::from_str::<T>(raw_response) {
+        Ok(data) => Ok(data),
+        Err(_) => {
+            if raw_response.is_empty() {
+                Err(ClientError::General(String::from("Empty response")))
+            } else {
+                Err(ClientError::UnexpectedResponseType(raw_response.to_string()))
+            }
+        },
+    }
+}
+
+/// Utility function to help a parameter list into a URL-encoded string.
+///
+/// Methods that take parameters now accept a [`ListParams`](struct.ListParams.html) instead.
+///
+/// ### Example
+///
+/// ```
+/// # #![allow(deprecated)]
+/// # use std::collections::HashMap;
+/// # use zoho_crm::parse_params;
+/// let mut params: HashMap<&str, &str> = HashMap::new();
+/// params.insert("page", "2");
+///
+/// let params = parse_params(params).unwrap();
+/// assert_eq!("page=2", &params);
+/// ```
+#[deprecated(note = "use ListParams")]
+pub fn parse_params(params: impl serde::ser::Serialize) -> Result<String, serde_urlencoded::ser::Error> {
+    serde_urlencoded::to_string(params)
+}
 
 #[cfg(test)]
+#[allow(clippy::get_first, clippy::useless_conversion, clippy::useless_format)]
 mod tests {
     extern crate mockito;
 
-    use mockito::{mock, Matcher, Mock};
-    use super::*;
-    use serde::Deserialize;
-    use std::collections::HashMap;
+    use mockito::{mock, Matcher, Mock};
+    use super::*;
+    use crate::{DeletedType, SearchQuery};
+    use serde::Deserialize;
+    use std::collections::HashMap;
+
+    #[derive(Debug, Deserialize)]
+    struct ResponseRecord {
+        id: String,
+    }
+
+    /// Get a `Client` with an access token.
+    fn get_client(access_token: Option<String>, api_domain: Option<String>) -> Client {
+        let id = String::from("id");
+        let secret = String::from("secret");
+        let refresh_token = String::from("refresh_token");
+
+        Client::with_creds(access_token, api_domain, id, secret, refresh_token)
+    }
+
+    /// Get an HTTP mocker.
+    fn get_mocker<T: Into<Matcher>>(method: &str, url: T, body: Option<&str>) -> Mock {
+        let mut mocker = mock(method, url)
+            .with_status(200)
+            .with_header("Content-Type", "application/json;charset=UTF-8");
+
+        if let Some(body) = body {
+            mocker = mocker
+                .with_header("Content-Length", &body.to_string().len().to_string())
+                .with_body(body);
+        }
+
+        mocker = mocker.create();
+
+        mocker
+    }
+
+    /// Get an HTTP mocker that responds with an empty body and the given status code.
+    fn get_empty_mocker<T: Into<Matcher>>(method: &str, url: T, status: usize) -> Mock {
+        mock(method, url)
+            .with_status(status)
+            .create()
+    }
+
+    #[test]
+    /// Tests that using no preset access token works.
+    fn no_access_token() {
+        let client = get_client(None, Some(String::from("api_domain")));
+
+        assert_eq!(client.access_token(), None);
+    }
+
+    #[test]
+    /// Tests that using no preset API domain works.
+    fn no_domain() {
+        let client = get_client(Some(String::from("access_token")), None);
+
+        assert_eq!(client.api_domain(), None);
+    }
+
+    #[test]
+    /// Tests that using a preset access token works.
+    fn preset_access_token() {
+        let access_token = String::from("access_token");
+        let client = get_client(Some(access_token.clone()), None);
+
+        assert_eq!(client.access_token(), Some(access_token));
+    }
+
+    #[test]
+    /// Tests that using a preset API domain works.
+    fn preset_api_domain() {
+        let domain = String::from("api_domain");
+        let client = get_client(None, Some(domain.clone()));
+
+        assert_eq!(client.api_domain(), Some(domain));
+    }
+
+    #[test]
+    /// Tests that the `valid_abbreviated_token()` method works without an access token.
+    fn empty_abbreviated_token() {
+        let client = get_client(None, None);
+
+        assert_eq!(client.abbreviated_access_token(), None);
+    }
+
+    #[test]
+    /// Tests that the `valid_abbreviated_token()` method works with an access token.
+    fn valid_abbreviated_token() {
+        let access_token = String::from("12345678901234567890");
+        let client = get_client(Some(access_token), None);
+
+        assert_ne!(client.access_token().unwrap().len(), 15);
+        assert_eq!(client.abbreviated_access_token().unwrap().len(), 15);
+    }
+
+    #[test]
+    fn api_domain() {
+        let api_domain = "https://test.com";
+        let client = get_client(None, Some(api_domain.to_string()));
+
+        assert_eq!(api_domain, client.api_domain().unwrap());
+    }
+
+    #[test]
+    fn api_domain_sandbox() {
+        let api_domain = "https://test.com";
+        let sandbox_api_domain = "https://crmsandbox.zoho.com";
+
+        let id = String::from("id");
+        let secret = String::from("secret");
+        let refresh_token = String::from("refresh_token");
+
+        let mut client = Client::with_creds(None, Some(api_domain.to_string()), id, secret, refresh_token);
+        client.set_sandbox(true);
+
+        assert_eq!(sandbox_api_domain, client.api_domain().unwrap());
+    }
+
+    #[test]
+    /// Tests that a valid token is set after calling the `Client` `get_new_token()` method.
+    fn get_new_token_success() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = "https://www.zohoapis.com";
+        let body = format!("{{\"access_token\":\"{}\",\"expires_in_sec\":3600,\"api_domain\":\"{}\",\"token_type\":\"Bearer\",\"expires_in\":3600000}}", access_token, api_domain);
+        let mocker = get_mocker("POST", Matcher::Any, Some(&body));
+        let mut client = get_client(None, None);
+
+        match client.get_new_token() {
+            Ok(e) => println!("Good: {:#?}", e),
+            Err(error) => println!("Bad: {:#?}", error),
+        }
+
+        mocker.assert();
+        assert_eq!(client.access_token(), Some(access_token.to_string()));
+    }
+
+    #[test]
+    /// Tests that a valid API domain is set after calling the `Client` `get_new_token()` method.
+    fn get_new_api_domain_success() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = "https://www.zohoapis.com";
+        let body = format!(r#"{{"access_token":"{}","expires_in_sec":3600,"api_domain":"{}","token_type":"Bearer","expires_in":3600000}}"#, access_token, api_domain);
+        let mocker = get_mocker("POST", Matcher::Any, Some(&body));
+        let mut client = get_client(None, None);
+
+        client.get_new_token().unwrap();
+
+        mocker.assert();
+        assert_eq!(client.api_domain(), Some(api_domain.to_string()));
+    }
+
+    #[test]
+    /// Tests that an error is return after calling the `Client` `get_new_token()` method with an
+    /// invalid refresh token.
+    fn get_new_token_invalid_token() {
+        let error_message = "invalid_token";
+        let body = format!(r#"{{"error":"{}"}}"#, error_message);
+        let mocker = get_mocker("POST", Matcher::Any, Some(&body));
+        let mut client = get_client(None, None);
+
+        match client.get_new_token() {
+            Ok(_) => panic!("Error was not thrown"),
+            Err(error) => {
+                assert_eq!(error_message.to_string(), error.to_string());
+            }
+        }
+
+        mocker.assert();
+    }
+
+    #[test]
+    /// Tests that a `TokenRecord` with a valid access token is returned from the `Client`
+    /// `get_new_token()` method.
+    fn return_new_token_success() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = "https://www.zohoapis.com";
+        let body = format!(r#"{{"access_token":"{}","expires_in_sec":3600,"api_domain":"{}","token_type":"Bearer","expires_in":3600000}}"#, access_token, api_domain);
+        let mocker = get_mocker("POST", Matcher::Any, Some(&body));
+        let mut client = get_client(None, None);
+
+        let token = client.get_new_token().unwrap();
+
+        mocker.assert();
+        assert_eq!(token.access_token, Some(access_token.to_string()));
+    }
+
+    #[test]
+    /// Tests that a `TokenRecord` with a valid API domain is returned from the `Client`
+    /// `get_new_token()` method.
+    fn return_api_domain_success() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = "https://www.zohoapis.com";
+        let body = format!(r#"{{"access_token":"{}","expires_in_sec":3600,"api_domain":"{}","token_type":"Bearer","expires_in":3600000}}"#, access_token, api_domain);
+        let mocker = get_mocker("POST", Matcher::Any, Some(&body));
+        let mut client = get_client(None, None);
+
+        let token = client.get_new_token().unwrap();
+
+        mocker.assert();
+        assert_eq!(token.api_domain, Some(api_domain.to_string()));
+    }
+
+    #[test]
+    /// Tests that fetching a record via the `get()` method works.
+    fn get_success() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let record_id = "40000000123456789";
+        let body = format!(r#"{{"data":[{{"id":"{}"}}],"info":{{"more_records":true,"per_page":1,"count":1,"page":1}}}}"#, record_id);
+        let mocker = get_mocker("GET", Matcher::Any, Some(&body));
+        let mut client = get_client(Some(String::from(access_token)), Some(String::from(api_domain)));
+
+        let response = client.get::<ResponseRecord>("Accounts", record_id).unwrap();
+
+        mocker.assert();
+        assert_eq!(response.data.get(0).unwrap().id, record_id);
+    }
+
+    #[test]
+    /// Tests that an error code returned via the `get()` method returns an error.
+    fn get_regular_error() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let error_code = "INVALID_URL_PATTERN";
+        let body = format!(r#"{{"code":"{}","details":{{}},"message":"Please check if the URL trying to access is a correct one","status":"error"}}"#, error_code);
+        let mocker = get_mocker("GET", Matcher::Any, Some(&body));
+        let mut client = get_client(Some(String::from(access_token)), Some(String::from(api_domain)));
+
+        match client.get::<ResponseRecord>("INVALID_MODULE", "00000") {
+            Ok(_) => panic!("Response did not return an error"),
+            Err(err) => {
+                match err {
+                    ClientError::ApiError(error) => assert_eq!(error.code, error_code),
+                    _ => panic!("Wrong error type"),
+                }
+            }
+        }
+
+        mocker.assert();
+    }
+
+    #[test]
+    /// Tests that a plain error message returned via the `get()` method returns an error.
+    fn get_text_error() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let error_code = "invalid_client";
+        let body = format!("{}", error_code);
+        let mocker = get_mocker("GET", Matcher::Any, Some(&body));
+        let mut client = get_client(Some(String::from(access_token)), Some(String::from(api_domain)));
+
+        match client.get::<ResponseRecord>("INVALID_MODULE", "00000") {
+            Ok(_) => panic!("Response did not return an error"),
+            Err(err) => {
+                assert_eq!(err.to_string(), error_code.to_string());
+            }
+        }
+
+        mocker.assert();
+    }
+
+    #[test]
+    /// Tests that inserting a record via the `insert()` method works.
+    fn insert_many_success() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let record_id = "40000000123456789";
+        let body = format!(r#"{{
+            "data": [
+                {{
+                    "code": "SUCCESS",
+                    "details": {{
+                        "Modified_Time": "2019-05-02T11:17:33+05:30",
+                        "Modified_By": {{
+                            "name": "Patricia Boyle",
+                            "id": "554023000000235011"
+                        }},
+                        "Created_Time": "2019-05-02T11:17:33+05:30",
+                        "id": "{}",
+                        "Created_By": {{
+                            "name": "Patricia Boyle",
+                            "id": "554023000000235011"
+                        }}
+                    }},
+                    "message": "record added",
+                    "status": "success"
+                }}
+            ]
+        }}"#, record_id);
+        let mocker = get_mocker("POST", Matcher::Any, Some(&body));
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain.to_string()));
+
+        let mut record: HashMap<&str, &str> = HashMap::new();
+        record.insert("name", "New Record Name");
+
+        let response = client.insert("Accounts", vec![record]).unwrap();
+        let response = response.data.get(0).unwrap();
+
+        let details = match &response.details {
+            response::ResponseDataItemDetails::Error(_) => {
+                panic!("Experienced an unexpected error");
+            },
+            response::ResponseDataItemDetails::Success(details) => details,
+        };
+
+        mocker.assert();
+        assert_eq!(details.id, record_id);
+    }
+
+    #[test]
+    /// Tests that an error code returned via the `insert()` method returns an error.
+    fn insert_regular_error() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let error_code = "INVALID_MODULE";
+        let body = format!(r#"{{
+            "code": "{}",
+            "details": {{}},
+            "message": "Please check if the URL trying to access is a correct one",
+            "status": "error"
+        }}"#, error_code);
+        let mocker = get_mocker("POST", Matcher::Any, Some(&body));
+        let mut client = get_client(Some(String::from(access_token)), Some(String::from(api_domain)));
+
+        let mut record: HashMap<&str, &str> = HashMap::new();
+        record.insert("name", "New Record Name");
+
+        match client.insert("INVALID_MODULE", vec![record]) {
+            Ok(_) => panic!("Response did not return an error"),
+            Err(err) => {
+                match err {
+                    ClientError::ApiError(error) => assert_eq!(error.code, error_code),
+                    _ => panic!("Wrong error type"),
+                }
+            }
+        }
+
+        mocker.assert();
+    }
+
+    #[test]
+    /// Tests that a plain error message returned via the `insert()` method returns an error.
+    fn insert_many_text_error() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let error_code = "invalid_client";
+        let body = format!("{}", error_code);
+        let mocker = get_mocker("POST", Matcher::Any, Some(&body));
+        let mut client = get_client(Some(String::from(access_token)), Some(String::from(api_domain)));
+
+        let mut record: HashMap<&str, &str> = HashMap::new();
+        record.insert("name", "New Record Name");
+
+        match client.insert("INVALID_MODULE", vec![record]) {
+            Ok(_) => panic!("Response did not return an error"),
+            Err(err) => {
+                assert_eq!(err.to_string(), error_code.to_string());
+            }
+        }
+
+        mocker.assert();
+    }
+
+    #[test]
+    /// Tests that updating a record via the `update_many()` method works.
+    fn update_many_success() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let record_id = "40000000123456789";
+        let body = format!(r#"{{
+            "data": [
+                {{
+                    "code": "SUCCESS",
+                    "details": {{
+                      "Modified_Time": "2019-05-02T11:17:33+05:30",
+                      "Modified_By": {{
+                        "name": "Patricia Boyle",
+                        "id": "554023000000235011"
+                      }},
+                      "Created_Time": "2019-05-02T11:17:33+05:30",
+                      "id": "{}",
+                      "Created_By": {{
+                        "name": "Patricia Boyle",
+                        "id": "554023000000235011"
+                      }}
+                    }},
+                    "message": "record updated",
+                    "status": "success"
+                }}
+            ]
+        }}"#, record_id);
+        let mocker = get_mocker("PUT", Matcher::Any, Some(&body));
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain.to_string()));
+
+        let mut record: HashMap<&str, &str> = HashMap::new();
+        record.insert("name", "New Record Name");
+
+        let response = client.update_many("Accounts", vec![record]).unwrap();
+        let response = response.data.get(0).unwrap();
+
+        let details = match &response.details {
+            response::ResponseDataItemDetails::Error(_) => {
+                panic!("Experienced an unexpected error");
+            },
+            response::ResponseDataItemDetails::Success(details) => details,
+        };
+
+        mocker.assert();
+        assert_eq!(details.id, record_id);
+    }
+
+    #[test]
+    /// Tests that an error code returned via the `update_many()` method returns an error.
+    fn update_regular_error() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let error_code = "INVALID_MODULE";
+        let body = format!(r#"{{
+            "code": "{}",
+            "details": {{}},
+            "message": "Please check if the URL trying to access is a correct one",
+            "status": "error"
+        }}"#, error_code);
+        let mocker = get_mocker("PUT", Matcher::Any, Some(&body));
+        let mut client = get_client(Some(String::from(access_token)), Some(String::from(api_domain)));
+
+        let mut record: HashMap<&str, &str> = HashMap::new();
+        record.insert("name", "New Record Name");
+
+        match client.update_many("INVALID_MODULE", vec![record]) {
+            Ok(_) => panic!("Response did not return an error"),
+            Err(err) => {
+                match err {
+                    ClientError::ApiError(error) => assert_eq!(error.code, error_code),
+                    _ => panic!("Wrong error type"),
+                }
+            }
+        }
+
+        mocker.assert();
+    }
+
+    #[test]
+    /// Tests that a plain error message returned via the `update_many()` method returns an error.
+    fn update_many_text_error() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let error_code = "invalid_client";
+        let body = format!("{}", error_code);
+        let mocker = get_mocker("PUT", Matcher::Any, Some(&body));
+        let mut client = get_client(Some(String::from(access_token)), Some(String::from(api_domain)));
+
+        let mut record: HashMap<&str, &str> = HashMap::new();
+        record.insert("name", "New Record Name");
+
+        match client.update_many("INVALID_MODULE", vec![record]) {
+            Ok(_) => panic!("Response did not return an error"),
+            Err(err) => {
+                assert_eq!(err.to_string(), error_code.to_string());
+            }
+        }
+
+        mocker.assert();
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_parse_params() {
+        let mut params: HashMap<&str, &str> = HashMap::new();
+        params.insert("cvid", "00000");
+        params.insert("page", "2");
+
+        let converted = parse_params(params).unwrap();
+
+        match converted.as_str() {
+            "page=2&cvid=00000" => (),
+            "cvid=00000&page=2" => (),
+            _ => {
+                panic!("Params did not convert properly");
+            }
+        }
+    }
+
+    #[test]
+    /// Tests that fetching a record via the `get_with_fields()` method sends the fields.
+    fn get_with_fields_success() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let record_id = "40000000123456789";
+        let body = format!(r#"{{"data":[{{"id":"{}"}}]}}"#, record_id);
+        let mocker = get_mocker("GET", "/crm/v2/Accounts/40000000123456789?fields=id%2CAccount_Name", Some(&body));
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let response = client.get_with_fields::<ResponseRecord>("Accounts", record_id, &["id", "Account_Name"]).unwrap();
+
+        mocker.assert();
+        assert_eq!(response.data.first().unwrap().id, record_id);
+    }
+
+    #[test]
+    /// Tests that fetching a record via the `get_with_fields()` method with no fields leaves
+    /// out the `fields` parameter.
+    fn get_with_fields_empty() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let record_id = "40000000123456790";
+        let body = format!(r#"{{"data":[{{"id":"{}"}}]}}"#, record_id);
+        let mocker = get_mocker("GET", "/crm/v2/Accounts/40000000123456790", Some(&body));
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let response = client.get_with_fields::<ResponseRecord>("Accounts", record_id, &[]).unwrap();
+
+        mocker.assert();
+        assert_eq!(response.data.first().unwrap().id, record_id);
+    }
+
+    #[test]
+    /// Tests that fetching a record via the `get_with_fields()` method with too many fields
+    /// returns an error without sending a request.
+    fn get_with_fields_too_many() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let mut client = get_client(Some(access_token.to_string()), Some(mockito::server_url()));
+        let fields = vec!["Field"; 51];
+
+        match client.get_with_fields::<ResponseRecord>("Accounts", "40000000123456789", &fields) {
+            Err(ClientError::InvalidParams(_)) => (),
+            _ => panic!("Wrong result"),
+        }
+    }
+
+    #[test]
+    /// Tests that fetching records via the `get_many()` method only asks for the type's fields.
+    fn get_many_for_type() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let body = r#"{"data":[{"id":"1"}],"info":{"more_records":false,"per_page":200,"count":1,"page":1}}"#;
+        let mocker = get_mocker("GET", "/crm/v2/Accounts?fields=id", Some(body));
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let params = ListParams::for_type::<ResponseRecord>();
+        let response = client.get_many::<ResponseRecord>("Accounts", Some(params)).unwrap();
+
+        mocker.assert();
+        assert_eq!(response.data.len(), 1);
+    }
+
+    #[test]
+    /// Tests that fetching records via the `get_many()` method sends the `If-Modified-Since` header.
+    fn get_many_modified_since() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let modified_since = "2019-07-25T15:26:49+05:30";
+        let mocker = mock("GET", "/crm/v2/Accounts")
+            .match_header("If-Modified-Since", modified_since)
+            .with_status(304)
+            .create();
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let params = ListParams {
+            modified_since: Some(modified_since.to_string()),
+            ..ListParams::default()
+        };
+        let response = client.get_many::<ResponseRecord>("Accounts", Some(params)).unwrap();
+
+        mocker.assert();
+        assert!(response.data.is_empty());
+    }
+
+    #[test]
+    /// Tests that invalid parameters via the `get_many()` method return an error without a request.
+    fn get_many_invalid_params() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let mocker = get_mocker("GET", Matcher::Any, None).expect(0);
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let params = ListParams {
+            per_page: Some(500),
+            ..ListParams::default()
+        };
+
+        match client.get_many::<ResponseRecord>("Accounts", Some(params)) {
+            Err(ClientError::InvalidParams(_)) => (),
+            _ => panic!("Invalid parameters were accepted"),
+        }
+
+        mocker.assert();
+    }
+
+    #[test]
+    /// Tests that fetching deleted records via the `get_deleted()` method works.
+    fn get_deleted_success() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let body = r#"{
+            "data": [
+                {
+                    "deleted_by": {
+                        "name": "Patricia Boyle",
+                        "id": "554023000000235011"
+                    },
+                    "id": "554023000000325001",
+                    "display_name": "Burns",
+                    "type": "recycle",
+                    "created_by": {
+                        "name": "Patricia Boyle",
+                        "id": "554023000000235011"
+                    },
+                    "deleted_time": "2019-05-02T11:17:33+05:30"
+                },
+                {
+                    "deleted_by": null,
+                    "id": "554023000000325002",
+                    "display_name": null,
+                    "type": "permanent",
+                    "created_by": null,
+                    "deleted_time": "2019-05-01T10:00:00+05:30"
+                }
+            ],
+            "info": {"per_page": 2, "count": 2, "page": 1, "more_records": false}
+        }"#;
+        let mocker = get_mocker("GET", "/crm/v2/Leads/deleted?type=all&per_page=2", Some(body));
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let params = DeletedParams {
+            per_page: Some(2),
+            ..DeletedParams::new(DeletedType::All)
+        };
+        let response = client.get_deleted("Leads", params).unwrap();
+
+        mocker.assert();
+        assert_eq!(response.data.len(), 2);
+
+        let recycled = &response.data[0];
+        assert_eq!(recycled.id, "554023000000325001");
+        assert_eq!(recycled.deleted_by.as_ref().unwrap().name.as_ref().unwrap(), "Patricia Boyle");
+        assert_eq!(recycled.display_name.as_ref().unwrap(), "Burns");
+        assert_eq!(recycled.deleted_type, "recycle");
+
+        let permanent = &response.data[1];
+        assert!(permanent.deleted_by.is_none());
+        assert_eq!(permanent.deleted_time.as_ref().unwrap(), "2019-05-01T10:00:00+05:30");
+    }
+
+    #[test]
+    /// Tests that fetching related records via the `get_related()` method works.
+    fn get_related_success() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let body = r#"{"data":[{"id":"1"},{"id":"2"}],"info":{"more_records":false,"per_page":200,"count":2,"page":1}}"#;
+        let mocker = get_mocker("GET", "/crm/v2/Accounts/100/Contacts", Some(body));
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let response = client.get_related::<ResponseRecord>("Accounts", "100", "Contacts", None).unwrap();
+
+        mocker.assert();
+        assert_eq!(response.data.len(), 2);
+    }
+
+    #[test]
+    /// Tests that a record without related records via the `get_related()` method returns no records.
+    fn get_related_no_content() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let mocker = get_empty_mocker("GET", "/crm/v2/Deals/100/Products", 204);
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let response = client.get_related::<ResponseRecord>("Deals", "100", "Products", None).unwrap();
+
+        mocker.assert();
+        assert!(response.data.is_empty());
+    }
+
+    #[test]
+    /// Tests that delinking related records via the `delist_related()` method works.
+    fn delist_related_success() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let body = r#"{
+            "data": [
+                {"code": "SUCCESS", "details": {"id": "200"}, "message": "relation removed", "status": "success"},
+                {"code": "SUCCESS", "details": {"id": "201"}, "message": "relation removed", "status": "success"}
+            ]
+        }"#;
+        let mocker = get_mocker("DELETE", "/crm/v2/Deals/100/Products?ids=200%2C201", Some(body));
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let response = client.delist_related("Deals", "100", "Products", &["200", "201"]).unwrap();
+
+        mocker.assert();
+
+        assert_eq!(response.data[1].status, "success");
+        assert_eq!(response.data[1].details.id.as_ref().unwrap(), "201");
+    }
+
+    #[test]
+    /// Tests that relating records via the `update_related()` method sends the records.
+    fn update_related_success() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let body = r#"{
+            "data": [
+                {"code": "SUCCESS", "details": {"id": "200"}, "message": "relation updated", "status": "success"},
+                {"code": "INVALID_DATA", "details": {"id": "201"}, "message": "the related id given seems to be invalid", "status": "error"}
+            ]
+        }"#;
+        let mocker = mock("PUT", "/crm/v2/Deals/100/Products")
+            .match_body(Matcher::JsonString(r#"{"data":[{"id":"200","Quantity":"2"},{"id":"201"}]}"#.to_string()))
+            .with_status(200)
+            .with_body(body)
+            .create();
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let mut first: HashMap<&str, &str> = HashMap::new();
+        first.insert("id", "200");
+        first.insert("Quantity", "2");
+        let mut second: HashMap<&str, &str> = HashMap::new();
+        second.insert("id", "201");
+
+        let response = client.update_related("Deals", "100", "Products", vec![first, second]).unwrap();
+
+        mocker.assert();
+        assert_eq!(response.data[0].details.id.as_ref().unwrap(), "200");
+        assert_eq!(response.data[1].code, "INVALID_DATA");
+        assert_eq!(response.data[1].details.id.as_ref().unwrap(), "201");
+    }
+
+    #[test]
+    /// Tests that iterating via the `iter_all()` method fetches every page.
+    fn iter_all_success() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let first_body = r#"{"data":[{"id":"1"},{"id":"2"}],"info":{"more_records":true,"per_page":2,"count":2,"page":1}}"#;
+        let second_body = r#"{"data":[{"id":"3"}],"info":{"more_records":false,"per_page":2,"count":1,"page":2}}"#;
+        let first_mocker = get_mocker("GET", "/crm/v2/Accounts?page=1&per_page=2", Some(first_body));
+        let second_mocker = get_mocker("GET", "/crm/v2/Accounts?page=2&per_page=2", Some(second_body));
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let ids: Vec<String> = client
+            .iter_all::<ResponseRecord>("Accounts", Some(ListParams { per_page: Some(2), ..ListParams::default() }))
+            .map(|record| record.unwrap().id)
+            .collect();
+
+        first_mocker.assert();
+        second_mocker.assert();
+        assert_eq!(ids, vec!["1", "2", "3"]);
+    }
+
+    #[test]
+    /// Tests that iterating via the `iter_all()` method follows the `next_page_token`.
+    fn iter_all_page_token() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let first_body = r#"{"data":[{"id":"1"}],"info":{"more_records":true,"per_page":1,"count":1,"page":1,"next_page_token":"c8582f+b/y=","page_token_expiry":"2022-11-11T15:08:14+05:30"}}"#;
+        let second_body = r#"{"data":[{"id":"2"}],"info":{"more_records":false,"per_page":1,"count":1,"page":2,"next_page_token":null}}"#;
+        let first_mocker = get_mocker("GET", "/crm/v2/Accounts?page=1", Some(first_body));
+        let second_mocker = get_mocker("GET", "/crm/v2/Accounts?page_token=c8582f%2Bb%2Fy%3D", Some(second_body));
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let ids: Vec<String> = client
+            .iter_all::<ResponseRecord>("Accounts", None)
+            .map(|record| record.unwrap().id)
+            .collect();
+
+        first_mocker.assert();
+        second_mocker.assert();
+        assert_eq!(ids, vec!["1", "2"]);
+    }
+
+    #[test]
+    /// Tests that an error via the `iter_all()` method is returned once, then iteration stops.
+    fn iter_all_error() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let body = r#"{"code":"INVALID_MODULE","details":{},"message":"the module name given seems to be invalid","status":"error"}"#;
+        let mocker = get_mocker("GET", Matcher::Any, Some(body));
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let mut records = client.iter_all::<ResponseRecord>("INVALID_MODULE", None);
+
+        assert!(records.next().unwrap().is_err());
+        assert!(records.next().is_none());
+        mocker.assert();
+    }
+
+    #[test]
+    /// Tests that searching records via the `search()` method works.
+    fn search_success() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let record_id = "40000000123456789";
+        let body = format!(r#"{{"data":[{{"id":"{}"}}],"info":{{"more_records":false,"per_page":10,"count":1,"page":1}}}}"#, record_id);
+        let mocker = get_mocker("GET", "/crm/v2/Contacts/search?email=burns%40example.com&per_page=10", Some(&body));
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let mut params = SearchParams::new(SearchQuery::Email(String::from("burns@example.com")));
+        params.per_page = Some(10);
+
+        let response = client.search::<ResponseRecord>("Contacts", params).unwrap();
+
+        mocker.assert();
+        assert_eq!(response.data.first().unwrap().id, record_id);
+    }
+
+    #[test]
+    /// Tests that a search without any matches via the `search()` method returns no records.
+    fn search_no_content() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let mocker = get_empty_mocker("GET", Matcher::Any, 204);
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let params = SearchParams::new(SearchQuery::Word(String::from("Burns")));
+        let response = client.search::<ResponseRecord>("Contacts", params).unwrap();
+
+        mocker.assert();
+        assert!(response.data.is_empty());
+        assert!(!response.info.more_records);
+    }
+
+    #[test]
+    /// Tests that running a COQL query via the `query()` method works.
+    fn query_success() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let record_id = "40000000123456789";
+        let body = format!(r#"{{"data":[{{"id":"{}"}}],"info":{{"count":1,"more_records":false}}}}"#, record_id);
+        let mocker = mock("POST", "/crm/v2/coql")
+            .match_body(Matcher::JsonString(String::from(r#"{"select_query":"select id from Contacts"}"#)))
+            .with_status(200)
+            .with_body(&body)
+            .create();
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let response = client.query::<ResponseRecord>("select id from Contacts").unwrap();
+
+        mocker.assert();
+        assert_eq!(response.data.first().unwrap().id, record_id);
+        assert_eq!(response.info.count, 1);
+    }
+
+    #[test]
+    /// Tests that a COQL query without any matches via the `query()` method returns no records.
+    fn query_no_content() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let mocker = get_empty_mocker("POST", "/crm/v2/coql", 204);
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let response = client.query::<ResponseRecord>("select id from Contacts").unwrap();
+
+        mocker.assert();
+        assert!(response.data.is_empty());
+    }
+
+    #[test]
+    /// Tests that fetching the notes of a record via the `record_notes()` method works.
+    fn record_notes_success() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let body = r#"{
+            "data": [
+                {
+                    "Owner": {"name": "Patricia Boyle", "id": "554023000000235011"},
+                    "Modified_Time": "2019-05-02T11:17:33+05:30",
+                    "Created_Time": "2019-05-02T11:17:33+05:30",
+                    "Parent_Id": {"name": "Burns", "id": "554023000000325001"},
+                    "$se_module": "Leads",
+                    "Note_Title": "Call summary",
+                    "Note_Content": "Customer asked for a quote.",
+                    "id": "554023000000377001"
+                }
+            ],
+            "info": {"per_page": 200, "count": 1, "page": 1, "more_records": false}
+        }"#;
+        let mocker = get_mocker("GET", "/crm/v2/Leads/554023000000325001/Notes", Some(body));
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let response = client.record_notes("Leads", "554023000000325001", None).unwrap();
+        let note = response.data.first().unwrap();
+
+        mocker.assert();
+        assert_eq!(note.note_title.as_ref().unwrap(), "Call summary");
+        assert_eq!(note.parent_id.as_ref().unwrap().id, "554023000000325001");
+        assert_eq!(note.se_module.as_ref().unwrap(), "Leads");
+        assert_eq!(note.owner.as_ref().unwrap().id, "554023000000235011");
+    }
+
+    #[test]
+    /// Tests that creating a note via the `create_notes()` method sends the note fields.
+    fn create_notes_success() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let body = r#"{
+            "data": [
+                {
+                    "code": "SUCCESS",
+                    "details": {
+                        "Modified_Time": "2019-05-02T11:17:33+05:30",
+                        "Created_Time": "2019-05-02T11:17:33+05:30",
+                        "id": "554023000000377001"
+                    },
+                    "message": "record added",
+                    "status": "success"
+                }
+            ]
+        }"#;
+        let mocker = mock("POST", "/crm/v2/Notes")
+            .match_body(Matcher::JsonString(String::from(r#"{"data":[{"Note_Title":"Call summary","Parent_Id":"100","se_module":"Leads"}]}"#)))
+            .with_status(201)
+            .with_body(body)
+            .create();
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let note = NoteData {
+            note_title: Some(String::from("Call summary")),
+            parent_id: Some(String::from("100")),
+            se_module: Some(String::from("Leads")),
+            ..NoteData::default()
+        };
+        let response = client.create_notes(vec![note]).unwrap();
+
+        mocker.assert();
+        assert_eq!(response.data.first().unwrap().code, "SUCCESS");
+    }
+
+    #[test]
+    /// Tests that deleting notes via the `delete_notes()` method returns the ID of each note.
+    fn delete_notes_success() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let body = r#"{
+            "data": [
+                {"code": "SUCCESS", "details": {"id": "554023000000378001"}, "message": "record deleted", "status": "success"},
+                {"code": "INVALID_DATA", "details": {"id": "0"}, "message": "the related id given seems to be invalid", "status": "error"}
+            ]
+        }"#;
+        let mocker = get_mocker("DELETE", "/crm/v2/Notes?ids=554023000000378001%2C0", Some(body));
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let response = client.delete_notes(&["554023000000378001", "0"]).unwrap();
+
+        mocker.assert();
+        assert_eq!(response.data[0].status, "success");
+        assert_eq!(response.data[0].details.id.as_ref().unwrap(), "554023000000378001");
+        assert_eq!(response.data[1].code, "INVALID_DATA");
+    }
+
+    #[test]
+    /// Tests that deleting a note of a record via the `delete_record_note()` method works.
+    fn delete_record_note_success() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let body = r#"{"data": [{"code": "SUCCESS", "details": {"id": "554023000000378002"}, "message": "record deleted", "status": "success"}]}"#;
+        let mocker = get_mocker("DELETE", "/crm/v2/Leads/100/Notes/554023000000378002", Some(body));
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let response = client.delete_record_note("Leads", "100", "554023000000378002").unwrap();
+
+        mocker.assert();
+        assert_eq!(response.data[0].details.id.as_ref().unwrap(), "554023000000378002");
+    }
+
+    #[test]
+    /// Tests that uploading a file via the `upload_attachment()` method sends it as multipart data.
+    fn upload_attachment_success() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let body = r#"{
+            "data": [
+                {
+                    "code": "SUCCESS",
+                    "details": {
+                        "Modified_Time": "2019-05-02T11:17:33+05:30",
+                        "Created_Time": "2019-05-02T11:17:33+05:30",
+                        "id": "554023000000378001"
+                    },
+                    "message": "attachment uploaded successfully",
+                    "status": "success"
+                }
+            ]
+        }"#;
+        let mocker = mock("POST", "/crm/v2/Deals/100/Attachments")
+            .match_header("Content-Type", Matcher::Regex(String::from("^multipart/form-data")))
+            .match_body(Matcher::Regex(String::from(r#"filename="quote.txt"[\s\S]*quote contents"#)))
+            .with_status(200)
+            .with_body(body)
+            .create();
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let file = std::io::Cursor::new(b"quote contents".to_vec());
+        let response = client.upload_attachment("Deals", "100", file, "quote.txt").unwrap();
+
+        mocker.assert();
+        assert_eq!(response.data.first().unwrap().code, "SUCCESS");
+    }
+
+    #[test]
+    /// Tests that downloading a file via the `download_attachment()` method streams the file.
+    fn download_attachment_success() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let mocker = mock("GET", "/crm/v2/Deals/100/Attachments/200")
+            .with_status(200)
+            .with_header("Content-Type", "application/pdf")
+            .with_header("Content-Disposition", r#"attachment; filename="quote.pdf""#)
+            .with_body("%PDF-1.4")
+            .create();
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let mut download = client.download_attachment("Deals", "100", "200").unwrap();
+        let mut contents = String::new();
+        download.read_to_string(&mut contents).unwrap();
+
+        mocker.assert();
+        assert_eq!(download.content_type(), Some("application/pdf"));
+        assert_eq!(download.file_name(), Some(String::from("quote.pdf")));
+        assert_eq!(contents, "%PDF-1.4");
+    }
+
+    #[test]
+    /// Tests that an error code returned via the `download_attachment()` method returns an error.
+    fn download_attachment_error() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let error_code = "INVALID_DATA";
+        let body = format!(r#"{{"code":"{}","details":{{}},"message":"the related id given seems to be invalid","status":"error"}}"#, error_code);
+        let mocker = mock("GET", "/crm/v2/Deals/100/Attachments/000")
+            .with_status(400)
+            .with_body(&body)
+            .create();
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        match client.download_attachment("Deals", "100", "000") {
+            Err(ClientError::ApiError(error)) => assert_eq!(error.code, error_code),
+            _ => panic!("Response did not return an error"),
+        }
+
+        mocker.assert();
+    }
+
+    #[test]
+    /// Tests that deleting an attachment via the `delete_attachment()` method returns its ID.
+    fn delete_attachment_success() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let body = r#"{"data": [{"code": "SUCCESS", "details": {"id": "554023000000378001"}, "message": "record deleted", "status": "success"}]}"#;
+        let mocker = get_mocker("DELETE", "/crm/v2/Deals/100/Attachments/554023000000378001", Some(body));
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let response = client.delete_attachment("Deals", "100", "554023000000378001").unwrap();
+
+        mocker.assert();
+        assert_eq!(response.data[0].status, "success");
+        assert_eq!(response.data[0].details.id.as_ref().unwrap(), "554023000000378001");
+    }
+
+    #[test]
+    /// Tests that downloading a record image via the `get_photo()` method works.
+    fn get_photo_success() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let mocker = mock("GET", "/crm/v2/Contacts/100/photo")
+            .with_status(200)
+            .with_header("Content-Type", "image/png")
+            .with_body(&[0x89, 0x50, 0x4e, 0x47][..])
+            .create();
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let photo = client.get_photo("Contacts", "100").unwrap();
+
+        mocker.assert();
+        assert_eq!(photo.content_type.unwrap(), "image/png");
+        assert_eq!(photo.data, vec![0x89, 0x50, 0x4e, 0x47]);
+    }
+
+    #[test]
+    /// Tests that deleting a record image via the `delete_photo()` method works.
+    fn delete_photo_success() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let body = r#"{"code":"SUCCESS","details":{},"message":"Photo deleted","status":"success"}"#;
+        let mocker = get_mocker("DELETE", "/crm/v2/Contacts/100/photo", Some(body));
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let response = client.delete_photo("Contacts", "100").unwrap();
+
+        mocker.assert();
+        assert_eq!(response.code, "SUCCESS");
+    }
+
+    #[test]
+    /// Tests that converting a lead via the `convert_lead()` method returns the new record IDs.
+    fn convert_lead_success() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let body = r#"{"data":[{"Contacts":"200","Deals":null,"Accounts":"300"}]}"#;
+        let mocker = mock("POST", "/crm/v2/Leads/100/actions/convert")
+            .match_body(Matcher::JsonString(String::from(r#"{"data":[{"overwrite":true,"Accounts":"300"}]}"#)))
+            .with_status(200)
+            .with_body(body)
+            .create();
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let options = ConvertLeadOptions {
+            overwrite: Some(true),
+            account_id: Some(String::from("300")),
+            ..ConvertLeadOptions::default()
+        };
+        let converted = client.convert_lead("100", options).unwrap();
+
+        mocker.assert();
+        assert_eq!(converted.contact_id.unwrap(), "200");
+        assert_eq!(converted.account_id.unwrap(), "300");
+        assert!(converted.deal_id.is_none());
+    }
+
+    #[test]
+    /// Tests that a lead that cannot be converted via the `convert_lead()` method returns an error.
+    fn convert_lead_error() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let body = r#"{"data":[{"code":"DUPLICATE_DATA","details":{},"message":"the lead has already been converted","status":"error"}]}"#;
+        let mocker = get_mocker("POST", "/crm/v2/Leads/100/actions/convert", Some(body));
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        match client.convert_lead("100", ConvertLeadOptions::default()) {
+            Err(ClientError::ApiError(error)) => {
+                assert_eq!(error.code, "DUPLICATE_DATA");
+                assert_eq!(error.message, "the lead has already been converted");
+            },
+            _ => panic!("Response did not return an error"),
+        }
+
+        mocker.assert();
+    }
+
+    #[test]
+    /// Tests that fetching the tags of a module via the `tags()` method works.
+    fn tags_success() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let body = r#"{
+            "tags": [
+                {
+                    "created_time": "2019-05-02T11:17:33+05:30",
+                    "modified_time": "2019-05-02T11:17:33+05:30",
+                    "name": "Newsletter",
+                    "modified_by": {"name": "Patricia Boyle", "id": "554023000000235011"},
+                    "id": "554023000000379001",
+                    "created_by": {"name": "Patricia Boyle", "id": "554023000000235011"}
+                }
+            ],
+            "info": {"count": 1, "allowed_count": 100}
+        }"#;
+        let mocker = get_mocker("GET", "/crm/v2/settings/tags?module=Leads", Some(body));
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let response = client.tags("Leads").unwrap();
+
+        mocker.assert();
+        assert_eq!(response.tags.first().unwrap().name, "Newsletter");
+        assert_eq!(response.info.allowed_count, 100);
+    }
+
+    #[test]
+    /// Tests that a module without tags via the `tags()` method returns no tags.
+    fn tags_no_content() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let mocker = get_empty_mocker("GET", "/crm/v2/settings/tags?module=Deals", 204);
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let response = client.tags("Deals").unwrap();
 
-    #[derive(Debug, Deserialize)]
-    struct ResponseRecord {
-        id: String,
+        mocker.assert();
+        assert!(response.tags.is_empty());
+        assert_eq!(response.info.count, 0);
     }
 
-    /// Get a `Client` with an access token.
-    fn get_client(access_token: Option<String>, api_domain: Option<String>) -> Client {
-        let id = String::from("id");
-        let secret = String::from("secret");
-        let refresh_token = String::from("refresh_token");
+    #[test]
+    /// Tests that creating tags via the `create_tags()` method works.
+    fn create_tags_success() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let body = r#"{
+            "tags": [
+                {
+                    "code": "SUCCESS",
+                    "details": {"id": "554023000000379001", "name": "Newsletter"},
+                    "message": "tags created successfully",
+                    "status": "success"
+                }
+            ]
+        }"#;
+        let mocker = mock("POST", "/crm/v2/settings/tags?module=Leads")
+            .match_body(Matcher::JsonString(String::from(r#"{"tags":[{"name":"Newsletter"}]}"#)))
+            .with_status(201)
+            .with_body(body)
+            .create();
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
 
-        Client::with_creds(access_token, api_domain, id, secret, refresh_token)
+        let response = client.create_tags("Leads", &["Newsletter"]).unwrap();
+
+        mocker.assert();
+        assert_eq!(response.tags.first().unwrap().details.id.as_ref().unwrap(), "554023000000379001");
     }
 
-    /// Get an HTTP mocker.
-    fn get_mocker<T: Into<Matcher>>(method: &str, url: T, body: Option<&str>) -> Mock {
-        let mut mocker = mock(method, url)
-            .with_status(200)
-            .with_header("Content-Type", "application/json;charset=UTF-8");
+    #[test]
+    /// Tests that adding tags to records via the `add_tags_to_records()` method works.
+    fn add_tags_to_records_success() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let body = r#"{
+            "data": [
+                {"code": "SUCCESS", "details": {"id": "100", "tags": ["Newsletter", "Webinar"]}, "message": "tags updated successfully", "status": "success"},
+                {"code": "SUCCESS", "details": {"id": "101", "tags": ["Newsletter", "Webinar"]}, "message": "tags updated successfully", "status": "success"}
+            ]
+        }"#;
+        let mocker = get_mocker("POST", "/crm/v2/Leads/actions/add_tags?ids=100%2C101&tag_names=Newsletter%2CWebinar&over_write=false", Some(body));
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
 
-        if let Some(body) = body {
-            mocker = mocker
-                .with_header("Content-Length", &body.to_string().len().to_string())
-                .with_body(body);
-        }
+        let response = client.add_tags_to_records("Leads", &["100", "101"], &["Newsletter", "Webinar"], false).unwrap();
 
-        mocker = mocker.create();
+        mocker.assert();
+        assert_eq!(response.data.len(), 2);
+        assert_eq!(response.data[1].details.tags, vec!["Newsletter", "Webinar"]);
+    }
 
-        mocker
+    #[test]
+    /// Tests that starting a mass update job via the `mass_update()` method returns the job ID.
+    fn mass_update_success() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let body = r#"{
+            "data": [
+                {
+                    "status": "success",
+                    "code": "SCHEDULED",
+                    "message": "mass update scheduled successfully",
+                    "details": {"job_id": "554023000000460001"}
+                }
+            ]
+        }"#;
+        let mocker = mock("POST", "/crm/v2/Leads/actions/mass_update")
+            .match_body(Matcher::JsonString(String::from(r#"{"data":[{"Lead_Status":"Contacted"}],"ids":["100","101"]}"#)))
+            .with_status(202)
+            .with_body(body)
+            .create();
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let mut values = HashMap::new();
+        values.insert("Lead_Status", "Contacted");
+
+        let target = MassUpdateTarget::Ids(vec![String::from("100"), String::from("101")]);
+        let job_id = client.mass_update("Leads", target, values).unwrap();
+
+        mocker.assert();
+        assert_eq!(job_id, "554023000000460001");
     }
 
     #[test]
-    /// Tests that using no preset access token works.
-    fn no_access_token() {
-        let client = get_client(None, Some(String::from("api_domain")));
+    /// Tests that waiting for a mass update job returns the final status of the job.
+    fn wait_for_mass_update_success() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let body = r#"{
+            "data": [
+                {
+                    "Status": "COMPLETED",
+                    "Failed_Count": 0,
+                    "Updated_Count": 2,
+                    "Not_Updated_Count": 0,
+                    "Total_Count": 2
+                }
+            ]
+        }"#;
+        let mocker = get_mocker("GET", "/crm/v2/Leads/actions/mass_update?job_id=554023000000460001", Some(body));
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
 
-        assert_eq!(client.access_token(), None);
+        let status = client
+            .wait_for_mass_update("Leads", "554023000000460001", Duration::from_millis(0), Duration::from_secs(1))
+            .unwrap();
+
+        mocker.assert();
+        assert!(status.is_finished());
+        assert_eq!(status.updated_count, 2);
     }
 
     #[test]
-    /// Tests that using no preset API domain works.
-    fn no_domain() {
-        let client = get_client(Some(String::from("access_token")), None);
+    /// Tests that waiting for a mass update job gives up once the timeout has passed.
+    fn wait_for_mass_update_timeout() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let body = r#"{"data": [{"Status": "RUNNING", "Total_Count": 2}]}"#;
+        let mocker = get_mocker("GET", "/crm/v2/Leads/actions/mass_update?job_id=554023000000460001", Some(body));
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
 
-        assert_eq!(client.api_domain(), None);
+        let result = client.wait_for_mass_update("Leads", "554023000000460001", Duration::from_millis(10), Duration::from_millis(0));
+
+        mocker.assert();
+        assert!(result.is_err());
     }
 
     #[test]
-    /// Tests that using a preset access token works.
-    fn preset_access_token() {
-        let access_token = String::from("access_token");
-        let client = get_client(Some(access_token.clone()), None);
+    /// Tests that creating a Bulk Read job via the `create_bulk_read()` method works.
+    fn create_bulk_read_success() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let body = r#"{
+            "data": [
+                {
+                    "status": "success",
+                    "code": "ADDED_SUCCESSFULLY",
+                    "message": "Added successfully.",
+                    "details": {
+                        "id": "554023000000568002",
+                        "operation": "read",
+                        "state": "ADDED",
+                        "created_by": {"id": "554023000000235011", "name": "Patricia Boyle"},
+                        "created_time": "2019-05-09T14:01:24+05:30"
+                    }
+                }
+            ],
+            "info": {}
+        }"#;
+        let mocker = mock("POST", "/crm/bulk/v2/read")
+            .match_body(Matcher::JsonString(String::from(r#"{"query":{"module":"Leads","page":1}}"#)))
+            .with_status(201)
+            .with_body(body)
+            .create();
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let query = BulkReadQuery {
+            page: Some(1),
+            ..BulkReadQuery::new("Leads")
+        };
+        let job = client.create_bulk_read(query).unwrap();
 
-        assert_eq!(client.access_token(), Some(access_token));
+        mocker.assert();
+        assert_eq!(job.id, "554023000000568002");
+        assert!(!job.is_finished());
     }
 
     #[test]
-    /// Tests that using a preset API domain works.
-    fn preset_api_domain() {
-        let domain = String::from("api_domain");
-        let client = get_client(None, Some(domain.clone()));
+    /// Tests that fetching the progress of a Bulk Read job works.
+    fn bulk_read_status_success() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let body = r#"{
+            "data": [
+                {
+                    "id": "554023000000568002",
+                    "operation": "read",
+                    "state": "COMPLETED",
+                    "result": {
+                        "page": 1,
+                        "count": 2,
+                        "download_url": "/crm/bulk/v2/read/554023000000568002/result",
+                        "per_page": 200000,
+                        "more_records": false
+                    },
+                    "created_time": "2019-05-09T14:01:24+05:30",
+                    "file_type": "csv"
+                }
+            ]
+        }"#;
+        let mocker = get_mocker("GET", "/crm/bulk/v2/read/554023000000568002", Some(body));
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
 
-        assert_eq!(client.api_domain(), Some(domain));
+        let job = client.bulk_read_status("554023000000568002").unwrap();
+
+        mocker.assert();
+        assert!(job.is_finished());
+        assert_eq!(job.result.unwrap().count, 2);
     }
 
     #[test]
-    /// Tests that the `valid_abbreviated_token()` method works without an access token.
-    fn empty_abbreviated_token() {
-        let client = get_client(None, None);
+    /// Tests that the rows of a downloaded Bulk Read file are deserialized.
+    fn download_bulk_read_success() {
+        use std::io::Write;
 
-        assert_eq!(client.abbreviated_access_token(), None);
+        #[derive(Debug, Deserialize)]
+        struct Lead {
+            #[serde(rename = "Id")]
+            id: String,
+
+            #[serde(rename = "Last_Name")]
+            last_name: String,
+        }
+
+        let mut zipped = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        zipped.start_file("554023000000568002.csv", zip::write::FileOptions::default()).unwrap();
+        zipped.write_all(b"Id,Last_Name\n100,Burns\n101,Smithers\n").unwrap();
+        let zipped = zipped.finish().unwrap().into_inner();
+
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let mocker = mock("GET", "/crm/bulk/v2/read/554023000000568002/result")
+            .with_status(200)
+            .with_header("Content-Type", "application/zip")
+            .with_body(zipped)
+            .create();
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let mut result = client.download_bulk_read("554023000000568002").unwrap();
+        let leads: Vec<Lead> = result.rows().unwrap().collect::<Result<_, _>>().unwrap();
+
+        mocker.assert();
+        assert_eq!(leads.len(), 2);
+        assert_eq!(leads[1].id, "101");
+        assert_eq!(leads[1].last_name, "Smithers");
     }
 
     #[test]
-    /// Tests that the `valid_abbreviated_token()` method works with an access token.
-    fn valid_abbreviated_token() {
-        let access_token = String::from("12345678901234567890");
-        let client = get_client(Some(access_token), None);
+    /// Tests that uploading a Bulk Write file sends the organization header and returns the
+    /// file ID.
+    fn upload_bulk_write_success() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let body = r#"{
+            "status": "success",
+            "code": "FILE_UPLOAD_SUCCESS",
+            "message": "file uploaded.",
+            "details": {"file_id": "554023000000568002", "created_time": "2019-05-09T14:01:24+05:30"}
+        }"#;
+        let mocker = mock("POST", "/crm/v2/upload")
+            .match_header("feature", "bulk-write")
+            .match_header("X-CRM-ORG", "4150868")
+            .with_status(200)
+            .with_body(body)
+            .create();
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
 
-        assert_ne!(client.access_token().unwrap().len(), 15);
-        assert_eq!(client.abbreviated_access_token().unwrap().len(), 15);
+        let file = BulkWriteFile::from_csv("Last_Name\nBurns\n".as_bytes()).unwrap();
+        let file_id = client.upload_bulk_write("4150868", file).unwrap();
+
+        mocker.assert();
+        assert_eq!(file_id, "554023000000568002");
     }
 
     #[test]
-    fn api_domain() {
-        let api_domain = "https://test.com";
-        let client = get_client(None, Some(api_domain.to_string()));
+    /// Tests that the outcome of each row is read from a downloaded Bulk Write result file.
+    fn download_bulk_write_success() {
+        use std::io::Write;
 
-        assert_eq!(api_domain, client.api_domain().unwrap());
+        let mut zipped = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        zipped.start_file("554023000000568002.csv", zip::write::FileOptions::default()).unwrap();
+        zipped.write_all(b"Last_Name,STATUS,RECORD_ID,ERRORS\nBurns,ADDED,100,\n,SKIPPED,,MANDATORY_NOT_FOUND\n").unwrap();
+        let zipped = zipped.finish().unwrap().into_inner();
+
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let mocker = mock("GET", "/v2/crm/4150868/bulk-write/554023000000568002/554023000000568002.zip")
+            .with_status(200)
+            .with_body(zipped)
+            .create();
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain.clone()));
+
+        let url = format!("{}/v2/crm/4150868/bulk-write/554023000000568002/554023000000568002.zip", api_domain);
+        let rows: Vec<_> = client.download_bulk_write(&url).unwrap().rows().unwrap().collect::<Result<_, _>>().unwrap();
+
+        mocker.assert();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].record_id.as_ref().unwrap(), "100");
+        assert_eq!(rows[1].status, "SKIPPED");
+        assert_eq!(rows[1].errors.as_ref().unwrap(), "MANDATORY_NOT_FOUND");
     }
 
     #[test]
-    fn api_domain_sandbox() {
-        let api_domain = "https://test.com";
-        let sandbox_api_domain = "https://crmsandbox.zoho.com";
+    /// Tests that fetching the modules of an account via the `modules()` method works.
+    fn modules_success() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let body = r#"{
+            "modules": [
+                {
+                    "global_search_supported": true,
+                    "deletable": true,
+                    "creatable": true,
+                    "modified_time": null,
+                    "plural_label": "Leads",
+                    "presence_sub_menu": true,
+                    "id": "3652397000000002175",
+                    "visible": true,
+                    "convertable": true,
+                    "editable": true,
+                    "profiles": [{"name": "Administrator", "id": "3652397000000026011"}],
+                    "api_name": "Leads",
+                    "module_name": "Leads",
+                    "generated_type": "default",
+                    "singular_label": "Lead",
+                    "viewable": true,
+                    "api_supported": true,
+                    "modified_by": null
+                },
+                {
+                    "plural_label": "Vendors",
+                    "id": "3652397000000002209",
+                    "api_name": "Vendors_X",
+                    "module_name": "Vendors",
+                    "generated_type": "custom",
+                    "singular_label": "Vendor",
+                    "api_supported": false
+                }
+            ]
+        }"#;
+        let mocker = get_mocker("GET", "/crm/v2/settings/modules", Some(body));
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
 
-        let id = String::from("id");
-        let secret = String::from("secret");
-        let refresh_token = String::from("refresh_token");
+        let modules = client.modules().unwrap();
 
-        let mut client = Client::with_creds(None, Some(api_domain.to_string()), id, secret, refresh_token);
-        client.set_sandbox(true);
+        mocker.assert();
+        assert_eq!(modules.len(), 2);
+        assert!(modules[0].api_supported);
+        assert_eq!(modules[0].profiles.first().unwrap().id, "3652397000000026011");
+        assert_eq!(modules[1].api_name, "Vendors_X");
+        assert!(!modules[1].creatable);
+    }
 
-        assert_eq!(sandbox_api_domain, client.api_domain().unwrap());
+    #[test]
+    /// Tests that fetching the fields of a module via the `fields()` method works.
+    fn fields_success() {
+        use crate::metadata::DataType;
+
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let body = r#"{
+            "fields": [
+                {
+                    "system_mandatory": false,
+                    "read_only": false,
+                    "custom_field": false,
+                    "api_name": "Lead_Status",
+                    "field_label": "Lead Status",
+                    "data_type": "picklist",
+                    "length": 120,
+                    "id": "3652397000000002611",
+                    "lookup": {},
+                    "pick_list_values": [
+                        {"display_value": "-None-", "sequence_number": 1, "actual_value": "-None-"},
+                        {"display_value": "Contacted", "sequence_number": 2, "actual_value": "Contacted"}
+                    ]
+                },
+                {
+                    "system_mandatory": true,
+                    "api_name": "Account_Name",
+                    "field_label": "Account Name",
+                    "data_type": "lookup",
+                    "length": 120,
+                    "id": "3652397000000002613",
+                    "lookup": {"display_label": "Account Name", "api_name": "Account_Name", "module": "Accounts", "id": "3652397000000002613"},
+                    "pick_list_values": []
+                }
+            ]
+        }"#;
+        let mocker = get_mocker("GET", "/crm/v2/settings/fields?module=Contacts", Some(body));
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let fields = client.fields("Contacts").unwrap();
+
+        mocker.assert();
+        assert_eq!(fields[0].data_type, DataType::Picklist);
+        assert_eq!(fields[0].pick_list_values[1].actual_value, "Contacted");
+        assert_eq!(fields[0].lookup_module(), None);
+        assert!(fields[1].mandatory);
+        assert_eq!(fields[1].lookup_module(), Some("Accounts"));
     }
 
     #[test]
-    /// Tests that a valid token is set after calling the `Client` `get_new_token()` method.
-    fn get_new_token_success() {
+    /// Tests that fetching the layouts of a module via the `layouts()` method works.
+    fn layouts_success() {
         let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
-        let api_domain = "https://www.zohoapis.com";
-        let body = format!("{{\"access_token\":\"{}\",\"expires_in_sec\":3600,\"api_domain\":\"{}\",\"token_type\":\"Bearer\",\"expires_in\":3600000}}", access_token, api_domain);
-        let mocker = get_mocker("POST", Matcher::Any, Some(&body));
-        let mut client = get_client(None, None);
+        let api_domain = mockito::server_url();
+        let body = r#"{
+            "layouts": [
+                {
+                    "created_time": null,
+                    "modified_time": "2019-04-29T17:43:29+05:30",
+                    "visible": true,
+                    "name": "Standard",
+                    "id": "3652397000000091055",
+                    "status": 0,
+                    "profiles": [{"default": true, "name": "Administrator", "id": "3652397000000026011"}],
+                    "sections": [
+                        {
+                            "display_label": "Lead Information",
+                            "sequence_number": 1,
+                            "name": "Lead Information",
+                            "column_count": 2,
+                            "fields": [
+                                {"api_name": "Last_Name", "data_type": "text", "id": "3652397000000002597", "system_mandatory": true, "required": true}
+                            ]
+                        }
+                    ]
+                }
+            ]
+        }"#;
+        let mocker = get_mocker("GET", "/crm/v2/settings/layouts?module=Leads", Some(body));
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
 
-        match client.get_new_token() {
-            Ok(e) => println!("Good: {:#?}", e),
-            Err(error) => println!("Bad: {:#?}", error),
+        let layouts = client.layouts("Leads").unwrap();
+
+        mocker.assert();
+        assert_eq!(layouts[0].name, "Standard");
+        assert_eq!(layouts[0].sections[0].fields[0].api_name, "Last_Name");
+    }
+
+    #[test]
+    /// Tests that fetching a custom view via the `custom_view()` method includes its criteria.
+    fn custom_view_success() {
+        use crate::metadata::ViewCriteria;
+
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let body = r#"{
+            "custom_views": [
+                {
+                    "display_value": "Open Leads",
+                    "created_time": null,
+                    "default": false,
+                    "system_name": null,
+                    "name": "Open Leads",
+                    "modified_time": null,
+                    "id": "3652397000000087501",
+                    "category": "public_views",
+                    "fields": ["Full_Name", "Company", "Email"],
+                    "sort_by": null,
+                    "sort_order": null,
+                    "criteria": {"comparator": "not_equal", "field": "Lead_Status", "value": "Lost Lead"}
+                }
+            ]
+        }"#;
+        let mocker = get_mocker("GET", "/crm/v2/settings/custom_views/3652397000000087501?module=Leads", Some(body));
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let view = client.custom_view("Leads", "3652397000000087501").unwrap();
+
+        mocker.assert();
+        assert_eq!(view.fields, vec!["Full_Name", "Company", "Email"]);
+
+        match view.criteria {
+            Some(ViewCriteria::Condition { field, comparator, .. }) => {
+                assert_eq!(field, "Lead_Status");
+                assert_eq!(comparator, "not_equal");
+            },
+            _ => panic!("expected a condition"),
         }
+    }
+
+    #[test]
+    /// Tests that fetching users via the `users()` method sends the user type and page.
+    fn users_success() {
+        use crate::users::{UserType, UsersParams};
+
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let body = r#"{
+            "users": [
+                {
+                    "country": "US",
+                    "role": {"name": "CEO", "id": "3652397000000026005"},
+                    "language": "en_US",
+                    "locale": "en_US",
+                    "Modified_By": {"name": "Patricia Boyle", "id": "3652397000000186017"},
+                    "full_name": "Patricia Boyle",
+                    "profile": {"name": "Administrator", "id": "3652397000000026011"},
+                    "last_name": "Boyle",
+                    "time_zone": "Asia/Kolkata",
+                    "id": "3652397000000186017",
+                    "first_name": "Patricia",
+                    "email": "patricia.b@zylker.com",
+                    "status": "active",
+                    "confirm": true
+                }
+            ],
+            "info": {"per_page": 200, "count": 1, "page": 2, "more_records": false}
+        }"#;
+        let mocker = get_mocker("GET", "/crm/v2/users?type=ActiveUsers&page=2", Some(body));
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let params = UsersParams {
+            page: Some(2),
+            ..UsersParams::new(UserType::ActiveUsers)
+        };
+        let users = client.users(params).unwrap();
 
         mocker.assert();
-        assert_eq!(client.access_token(), Some(access_token.to_string()));
+        assert_eq!(users.info.page, 2);
+
+        let user = users.data.first().unwrap();
+        assert_eq!(user.role.as_ref().unwrap().name.as_ref().unwrap(), "CEO");
+        assert_eq!(user.profile.as_ref().unwrap().id, "3652397000000026011");
+        assert_eq!(user.time_zone.as_ref().unwrap(), "Asia/Kolkata");
     }
 
     #[test]
-    /// Tests that a valid API domain is set after calling the `Client` `get_new_token()` method.
-    fn get_new_api_domain_success() {
+    /// Tests that creating a user via the `create_user()` method sends the `users` envelope.
+    fn create_user_success() {
+        use crate::users::UserData;
+
         let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
-        let api_domain = "https://www.zohoapis.com";
-        let body = format!(r#"{{"access_token":"{}","expires_in_sec":3600,"api_domain":"{}","token_type":"Bearer","expires_in":3600000}}"#, access_token, api_domain);
-        let mocker = get_mocker("POST", Matcher::Any, Some(&body));
-        let mut client = get_client(None, None);
+        let api_domain = mockito::server_url();
+        let body = r#"{
+            "users": [
+                {"code": "SUCCESS", "details": {"id": "554023000000691003"}, "message": "User added", "status": "success"}
+            ]
+        }"#;
+        let mocker = mock("POST", "/crm/v2/users")
+            .match_body(Matcher::JsonString(String::from(r#"{"users":[{"last_name":"Smithers","email":"smithers@example.com"}]}"#)))
+            .with_status(201)
+            .with_body(body)
+            .create();
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let user = UserData {
+            last_name: Some(String::from("Smithers")),
+            email: Some(String::from("smithers@example.com")),
+            ..UserData::default()
+        };
+        let response = client.create_user(user).unwrap();
 
-        client.get_new_token().unwrap();
+        mocker.assert();
+        assert_eq!(response.users.first().unwrap().code, "SUCCESS");
+        assert_eq!(response.users.first().unwrap().details.id.as_ref().unwrap(), "554023000000691003");
+    }
+
+    #[test]
+    /// Tests that fetching a user via the `user()` method works.
+    fn user_success() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let body = r#"{
+            "users": [
+                {
+                    "id": "3652397000000186017",
+                    "last_name": "Boyle",
+                    "email": "patricia.b@zylker.com",
+                    "status": "active",
+                    "confirm": false
+                }
+            ]
+        }"#;
+        let mocker = get_mocker("GET", "/crm/v2/users/3652397000000186017", Some(body));
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let user = client.user("3652397000000186017").unwrap();
 
         mocker.assert();
-        assert_eq!(client.api_domain(), Some(api_domain.to_string()));
+        assert_eq!(user.last_name.unwrap(), "Boyle");
+        assert!(!user.confirm);
     }
 
     #[test]
-    /// Tests that an error is return after calling the `Client` `get_new_token()` method with an
-    /// invalid refresh token.
-    fn get_new_token_invalid_token() {
-        let error_message = "invalid_token";
-        let body = format!(r#"{{"error":"{}"}}"#, error_message);
-        let mocker = get_mocker("POST", Matcher::Any, Some(&body));
-        let mut client = get_client(None, None);
+    /// Tests that fetching an unknown user via the `user()` method returns an error.
+    fn user_error() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let body = r#"{"code":"INVALID_DATA","details":{},"message":"the id given seems to be invalid","status":"error"}"#;
+        let mocker = get_mocker("GET", "/crm/v2/users/0", Some(body));
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
 
-        match client.get_new_token() {
-            Ok(_) => panic!("Error was not thrown"),
-            Err(error) => {
-                assert_eq!(error_message.to_string(), error.to_string());
-            }
+        match client.user("0") {
+            Err(ClientError::ApiError(error)) => assert_eq!(error.code, "INVALID_DATA"),
+            _ => panic!("Wrong result"),
         }
 
         mocker.assert();
     }
 
     #[test]
-    /// Tests that a `TokenRecord` with a valid access token is returned from the `Client`
-    /// `get_new_token()` method.
-    fn return_new_token_success() {
+    /// Tests that updating a user via the `update_user()` method sends the `users` envelope.
+    fn update_user_success() {
+        use crate::users::UserData;
+
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let body = r#"{
+            "users": [
+                {"code": "SUCCESS", "details": {"id": "554023000000691003"}, "message": "User updated", "status": "success"}
+            ]
+        }"#;
+        let mocker = mock("PUT", "/crm/v2/users/554023000000691003")
+            .match_body(Matcher::JsonString(String::from(r#"{"users":[{"role":"554023000000015969","locale":"en_GB"}]}"#)))
+            .with_status(200)
+            .with_body(body)
+            .create();
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let user = UserData {
+            role: Some(String::from("554023000000015969")),
+            locale: Some(String::from("en_GB")),
+            ..UserData::default()
+        };
+        let response = client.update_user("554023000000691003", user).unwrap();
+
+        mocker.assert();
+        assert_eq!(response.users.first().unwrap().status, "success");
+        assert_eq!(response.users.first().unwrap().details.id.as_ref().unwrap(), "554023000000691003");
+    }
+
+    #[test]
+    /// Tests that deleting a user via the `delete_user()` method returns its status.
+    fn delete_user_success() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let body = r#"{
+            "users": [
+                {"code": "SUCCESS", "details": {}, "message": "User deleted", "status": "success"}
+            ]
+        }"#;
+        let mocker = get_mocker("DELETE", "/crm/v2/users/554023000000691004", Some(body));
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let response = client.delete_user("554023000000691004").unwrap();
+
+        mocker.assert();
+        assert_eq!(response.code, "SUCCESS");
+        assert_eq!(response.message, "User deleted");
+    }
+
+    #[test]
+    /// Tests that fetching the organization via the `org()` method works.
+    fn org_success() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let body = r#"{
+            "org": [
+                {
+                    "country": "United States",
+                    "mc_status": false,
+                    "alias": "zylker",
+                    "currency": "US Dollar - USD",
+                    "id": "3652397000000081001",
+                    "currency_symbol": "$",
+                    "currency_locale": "en_US",
+                    "time_zone": "America/Los_Angeles",
+                    "country_code": "US",
+                    "company_name": "Zylker",
+                    "primary_email": "patricia.b@zylker.com",
+                    "iso_code": "USD",
+                    "license_details": {"paid": true, "users_license_purchased": 10}
+                }
+            ]
+        }"#;
+        let mocker = get_mocker("GET", "/crm/v2/org", Some(body));
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let org = client.org().unwrap();
+
+        mocker.assert();
+        assert_eq!(org.iso_code.unwrap(), "USD");
+        assert_eq!(org.time_zone.unwrap(), "America/Los_Angeles");
+        assert!(!org.mc_status);
+    }
+
+    #[test]
+    /// Tests that fetching the roles of an organization via the `roles()` method works.
+    fn roles_success() {
         let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
-        let api_domain = "https://www.zohoapis.com";
-        let body = format!(r#"{{"access_token":"{}","expires_in_sec":3600,"api_domain":"{}","token_type":"Bearer","expires_in":3600000}}"#, access_token, api_domain);
-        let mocker = get_mocker("POST", Matcher::Any, Some(&body));
-        let mut client = get_client(None, None);
+        let api_domain = mockito::server_url();
+        let body = r#"{
+            "roles": [
+                {
+                    "display_label": "CEO",
+                    "forecast_manager": null,
+                    "share_with_peers": true,
+                    "name": "CEO",
+                    "description": "Users with this role have access to the data owned by all other users.",
+                    "id": "3652397000000026005",
+                    "reporting_to": null,
+                    "admin_user": true
+                },
+                {
+                    "display_label": "Manager",
+                    "share_with_peers": false,
+                    "name": "Manager",
+                    "description": null,
+                    "id": "3652397000000026008",
+                    "reporting_to": {"name": "CEO", "id": "3652397000000026005"},
+                    "admin_user": false
+                }
+            ]
+        }"#;
+        let mocker = get_mocker("GET", "/crm/v2/settings/roles", Some(body));
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
 
-        let token = client.get_new_token().unwrap();
+        let roles = client.roles().unwrap();
 
         mocker.assert();
-        assert_eq!(token.access_token, Some(access_token.to_string()));
+        assert_eq!(roles.len(), 2);
+        assert!(roles[0].reporting_to.is_none());
+        assert_eq!(roles[1].reporting_to.as_ref().unwrap().id, "3652397000000026005");
     }
 
     #[test]
-    /// Tests that a `TokenRecord` with a valid API domain is returned from the `Client`
-    /// `get_new_token()` method.
-    fn return_api_domain_success() {
+    /// Tests that fetching currencies via the `currencies()` method reads exchange rates exactly.
+    fn currencies_success() {
         let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
-        let api_domain = "https://www.zohoapis.com";
-        let body = format!(r#"{{"access_token":"{}","expires_in_sec":3600,"api_domain":"{}","token_type":"Bearer","expires_in":3600000}}"#, access_token, api_domain);
-        let mocker = get_mocker("POST", Matcher::Any, Some(&body));
-        let mut client = get_client(None, None);
+        let api_domain = mockito::server_url();
+        let body = r#"{
+            "currencies": [
+                {
+                    "symbol": "€",
+                    "created_time": "2019-06-10T13:08:01+05:30",
+                    "is_active": true,
+                    "exchange_rate": "0.850000000",
+                    "format": {"decimal_separator": "Period", "thousand_separator": "Comma", "decimal_places": "2"},
+                    "created_by": {"name": "Patricia Boyle", "id": "554023000000235011"},
+                    "prefix_symbol": true,
+                    "is_base": false,
+                    "modified_time": "2019-06-10T13:08:01+05:30",
+                    "name": "Euro - EUR",
+                    "modified_by": {"name": "Patricia Boyle", "id": "554023000000235011"},
+                    "id": "554023000001067008",
+                    "iso_code": "EUR"
+                }
+            ]
+        }"#;
+        let mocker = get_mocker("GET", "/crm/v2/org/currencies", Some(body));
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
 
-        let token = client.get_new_token().unwrap();
+        let currencies = client.currencies().unwrap();
 
         mocker.assert();
-        assert_eq!(token.api_domain, Some(api_domain.to_string()));
+        assert_eq!(currencies[0].iso_code, "EUR");
+        assert_eq!(currencies[0].exchange_rate.unwrap().to_string(), "0.850000000");
+        assert_eq!(currencies[0].format.as_ref().unwrap().decimal_places, "2");
     }
 
     #[test]
-    /// Tests that fetching a record via the `get()` method works.
-    fn get_success() {
+    /// Tests that updating currencies via the `update_currencies()` method sends exchange
+    /// rates as strings.
+    fn update_currencies_success() {
+        use crate::currency::CurrencyData;
+
         let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
         let api_domain = mockito::server_url();
-        let record_id = "40000000123456789";
-        let body = format!(r#"{{"data":[{{"id":"{}"}}],"info":{{"more_records":true,"per_page":1,"count":1,"page":1}}}}"#, record_id);
-        let mocker = get_mocker("GET", Matcher::Any, Some(&body));
-        let mut client = get_client(Some(String::from(access_token)), Some(String::from(api_domain)));
-
-        let response = client.get::<ResponseRecord>("Accounts", record_id).unwrap();
+        let body = r#"{
+            "currencies": [
+                {"code": "SUCCESS", "details": {"id": "554023000001067008"}, "message": "currency updated successfully", "status": "success"}
+            ]
+        }"#;
+        let mocker = mock("PUT", "/crm/v2/org/currencies")
+            .match_body(Matcher::JsonString(String::from(r#"{"currencies":[{"id":"554023000001067008","exchange_rate":"0.900000000"}]}"#)))
+            .with_status(200)
+            .with_body(body)
+            .create();
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let currency = CurrencyData {
+            id: Some(String::from("554023000001067008")),
+            exchange_rate: Some("0.900000000".parse().unwrap()),
+            ..CurrencyData::default()
+        };
+        let response = client.update_currencies(vec![currency]).unwrap();
+        let item = response.data.first().unwrap();
 
         mocker.assert();
-        assert_eq!(response.data.get(0).unwrap().id, record_id);
+        assert_eq!(item.status, "success");
+        assert_eq!(item.details.id.as_deref(), Some("554023000001067008"));
     }
 
     #[test]
-    /// Tests that an error code returned via the `get()` method returns an error.
-    fn get_regular_error() {
+    /// Tests that counting records matching a criteria via the `count()` method works.
+    fn count_criteria_success() {
         let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
         let api_domain = mockito::server_url();
-        let error_code = "INVALID_URL_PATTERN";
-        let body = format!(r#"{{"code":"{}","details":{{}},"message":"Please check if the URL trying to access is a correct one","status":"error"}}"#, error_code);
-        let mocker = get_mocker("GET", Matcher::Any, Some(&body));
-        let mut client = get_client(Some(String::from(access_token)), Some(String::from(api_domain)));
+        let mocker = get_mocker("GET", "/crm/v2/Leads/actions/count?criteria=%28Lead_Source%3Aequals%3AWeb%29", Some(r#"{"count": 42}"#));
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
 
-        match client.get::<ResponseRecord>("INVALID_MODULE", "00000") {
-            Ok(_) => panic!("Response did not return an error"),
-            Err(err) => {
-                match err {
-                    ClientError::ApiError(error) => assert_eq!(error.code, error_code),
-                    _ => panic!("Wrong error type"),
-                }
-            }
-        }
+        let count = client.count("Leads", Some(crate::Criteria::equals("Lead_Source", "Web").into())).unwrap();
 
         mocker.assert();
+        assert_eq!(count, 42);
     }
 
     #[test]
-    /// Tests that a plain error message returned via the `get()` method returns an error.
-    fn get_text_error() {
+    /// Tests that counting the records of a custom view via the `count()` method works.
+    fn count_cvid_success() {
         let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
         let api_domain = mockito::server_url();
-        let error_code = "invalid_client";
-        let body = format!("{}", error_code);
-        let mocker = get_mocker("GET", Matcher::Any, Some(&body));
-        let mut client = get_client(Some(String::from(access_token)), Some(String::from(api_domain)));
+        let mocker = get_mocker("GET", "/crm/v2/Leads/actions/count?cvid=3652397000000087501", Some(r#"{"count": 7}"#));
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
 
-        match client.get::<ResponseRecord>("INVALID_MODULE", "00000") {
-            Ok(_) => panic!("Response did not return an error"),
-            Err(err) => {
-                assert_eq!(err.to_string(), error_code.to_string());
-            }
-        }
+        let count = client.count("Leads", Some(CountFilter::Cvid(String::from("3652397000000087501")))).unwrap();
 
         mocker.assert();
+        assert_eq!(count, 7);
     }
 
     #[test]
-    /// Tests that inserting a record via the `insert()` method works.
-    fn insert_many_success() {
+    /// Tests that counting returns zero when Zoho sends back no content.
+    fn count_no_content() {
         let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
         let api_domain = mockito::server_url();
-        let record_id = "40000000123456789";
-        let body = format!(r#"{{
-            "data": [
-                {{
-                    "code": "SUCCESS",
-                    "details": {{
-                        "Modified_Time": "2019-05-02T11:17:33+05:30",
-                        "Modified_By": {{
-                            "name": "Patricia Boyle",
-                            "id": "554023000000235011"
-                        }},
-                        "Created_Time": "2019-05-02T11:17:33+05:30",
-                        "id": "{}",
-                        "Created_By": {{
-                            "name": "Patricia Boyle",
-                            "id": "554023000000235011"
-                        }}
-                    }},
-                    "message": "record added",
-                    "status": "success"
-                }}
-            ]
-        }}"#, record_id);
-        let mocker = get_mocker("POST", Matcher::Any, Some(&body));
-        let mut client = get_client(Some(access_token.to_string()), Some(api_domain.to_string()));
-
-        let mut record: HashMap<&str, &str> = HashMap::new();
-        record.insert("name", "New Record Name");
+        let mocker = get_empty_mocker("GET", "/crm/v2/Leads/actions/count?word=nothing", 204);
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
 
-        let response = client.insert("Accounts", vec![record]).unwrap();
-        let response = response.data.get(0).unwrap();
-
-        let details = match &response.details {
-            response::ResponseDataItemDetails::Error(_) => {
-                panic!("Experienced an unexpected error");
-            },
-            response::ResponseDataItemDetails::Success(details) => details,
-        };
+        let count = client.count("Leads", Some(SearchQuery::Word(String::from("nothing")).into())).unwrap();
 
         mocker.assert();
-        assert_eq!(details.id, record_id);
+        assert_eq!(count, 0);
     }
 
     #[test]
-    /// Tests that an error code returned via the `insert()` method returns an error.
-    fn insert_regular_error() {
+    /// Tests that streaming records via the `stream_all()` method follows every page.
+    fn stream_all_success() {
+        use futures::Stream;
+
         let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
         let api_domain = mockito::server_url();
-        let error_code = "INVALID_MODULE";
-        let body = format!(r#"{{
-            "code": "{}",
-            "details": {{}},
-            "message": "Please check if the URL trying to access is a correct one",
-            "status": "error"
-        }}"#, error_code);
-        let mocker = get_mocker("POST", Matcher::Any, Some(&body));
-        let mut client = get_client(Some(String::from(access_token)), Some(String::from(api_domain)));
-
-        let mut record: HashMap<&str, &str> = HashMap::new();
-        record.insert("name", "New Record Name");
+        let first_page = r#"{"data":[{"id":"1"},{"id":"2"}],"info":{"more_records":true,"per_page":2,"count":2,"page":1}}"#;
+        let second_page = r#"{"data":[{"id":"3"}],"info":{"more_records":false,"per_page":2,"count":1,"page":2}}"#;
+        let first_mocker = get_mocker("GET", "/crm/v2/Accounts?page=1&per_page=2", Some(first_page));
+        let second_mocker = get_mocker("GET", "/crm/v2/Accounts?page=2&per_page=2", Some(second_page));
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let params = ListParams {
+            per_page: Some(2),
+            ..ListParams::default()
+        };
+        let stream = client.stream_all::<ResponseRecord>("Accounts", Some(params));
+        let records = tokio::runtime::Runtime::new().unwrap().block_on(stream.collect()).unwrap();
 
-        match client.insert("INVALID_MODULE", vec![record]) {
-            Ok(_) => panic!("Response did not return an error"),
-            Err(err) => {
-                match err {
-                    ClientError::ApiError(error) => assert_eq!(error.code, error_code),
-                    _ => panic!("Wrong error type"),
-                }
-            }
-        }
+        first_mocker.assert();
+        second_mocker.assert();
 
-        mocker.assert();
+        let ids: Vec<&str> = records.iter().map(|record| record.id.as_str()).collect();
+        assert_eq!(ids, vec!["1", "2", "3"]);
     }
 
     #[test]
-    /// Tests that a plain error message returned via the `insert()` method returns an error.
-    fn insert_many_text_error() {
+    /// Tests that an error while streaming records via the `stream_all()` method ends the stream.
+    fn stream_all_error() {
+        use futures::Stream;
+
         let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
         let api_domain = mockito::server_url();
-        let error_code = "invalid_client";
-        let body = format!("{}", error_code);
-        let mocker = get_mocker("POST", Matcher::Any, Some(&body));
-        let mut client = get_client(Some(String::from(access_token)), Some(String::from(api_domain)));
-
-        let mut record: HashMap<&str, &str> = HashMap::new();
-        record.insert("name", "New Record Name");
+        let body = r#"{"code":"INVALID_MODULE","details":{},"message":"the module name given seems to be invalid","status":"error"}"#;
+        let mocker = get_mocker("GET", "/crm/v2/Nothing?page=1", Some(body));
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
 
-        match client.insert("INVALID_MODULE", vec![record]) {
-            Ok(_) => panic!("Response did not return an error"),
-            Err(err) => {
-                assert_eq!(err.to_string(), error_code.to_string());
-            }
-        }
+        let stream = client.stream_all::<ResponseRecord>("Nothing", None);
+        let result = tokio::runtime::Runtime::new().unwrap().block_on(stream.collect());
 
         mocker.assert();
+
+        match result {
+            Err(ClientError::ApiError(error)) => assert_eq!(error.code, "INVALID_MODULE"),
+            _ => panic!("Wrong result"),
+        }
     }
 
     #[test]
-    /// Tests that updating a record via the `update_many()` method works.
-    fn update_many_success() {
+    /// Tests that creating a Bulk Write job via the `create_bulk_write()` method returns the job ID.
+    fn create_bulk_write_success() {
+        use crate::bulk_write::{BulkWriteOperation, FieldMapping};
+
         let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
         let api_domain = mockito::server_url();
-        let record_id = "40000000123456789";
-        let body = format!(r#"{{
-            "data": [
-                {{
-                    "code": "SUCCESS",
-                    "details": {{
-                      "Modified_Time": "2019-05-02T11:17:33+05:30",
-                      "Modified_By": {{
-                        "name": "Patricia Boyle",
-                        "id": "554023000000235011"
-                      }},
-                      "Created_Time": "2019-05-02T11:17:33+05:30",
-                      "id": "{}",
-                      "Created_By": {{
-                        "name": "Patricia Boyle",
-                        "id": "554023000000235011"
-                      }}
-                    }},
-                    "message": "record updated",
-                    "status": "success"
-                }}
-            ]
-        }}"#, record_id);
-        let mocker = get_mocker("PUT", Matcher::Any, Some(&body));
-        let mut client = get_client(Some(access_token.to_string()), Some(api_domain.to_string()));
+        let body = r#"{
+            "status": "success",
+            "code": "SUCCESS",
+            "message": "success",
+            "details": {
+                "id": "554023000000568010",
+                "created_by": {"id": "554023000000235011", "name": "Patricia Boyle"}
+            }
+        }"#;
+        let request = r#"{"operation":"insert","resource":[{"type":"data","module":"Leads","file_id":"554023000000568002","field_mappings":[{"api_name":"Last_Name","index":0}]}]}"#;
+        let mocker = mock("POST", "/crm/bulk/v2/write")
+            .match_body(Matcher::JsonString(request.to_string()))
+            .with_status(201)
+            .with_body(body)
+            .create();
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let params = BulkWriteParams {
+            operation: BulkWriteOperation::Insert,
+            module: String::from("Leads"),
+            file_id: String::from("554023000000568002"),
+            find_by: None,
+            field_mappings: vec![FieldMapping::new("Last_Name", 0)],
+        };
+        let job_id = client.create_bulk_write(&params).unwrap();
 
-        let mut record: HashMap<&str, &str> = HashMap::new();
-        record.insert("name", "New Record Name");
+        mocker.assert();
+        assert_eq!(job_id, "554023000000568010");
+    }
 
-        let response = client.update_many("Accounts", vec![record]).unwrap();
-        let response = response.data.get(0).unwrap();
+    #[test]
+    /// Tests that an invalid Bulk Write job via the `create_bulk_write()` method returns an error.
+    fn create_bulk_write_error() {
+        use crate::bulk_write::BulkWriteOperation;
 
-        let details = match &response.details {
-            response::ResponseDataItemDetails::Error(_) => {
-                panic!("Experienced an unexpected error");
-            },
-            response::ResponseDataItemDetails::Success(details) => details,
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let api_domain = mockito::server_url();
+        let body = r#"{"status":"error","code":"INVALID_DATA","message":"invalid file id","details":{"api_name":"file_id"}}"#;
+        let mocker = mock("POST", "/crm/bulk/v2/write")
+            .with_status(400)
+            .with_body(body)
+            .create();
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let params = BulkWriteParams {
+            operation: BulkWriteOperation::Update,
+            module: String::from("Leads"),
+            file_id: String::from("0"),
+            find_by: Some(String::from("Email")),
+            field_mappings: vec![],
         };
 
+        match client.create_bulk_write(&params) {
+            Err(ClientError::ApiError(error)) => assert_eq!(error.code, "INVALID_DATA"),
+            _ => panic!("Wrong result"),
+        }
+
         mocker.assert();
-        assert_eq!(details.id, record_id);
     }
 
     #[test]
-    /// Tests that an error code returned via the `update_many()` method returns an error.
-    fn update_regular_error() {
+    /// Tests that fetching a Bulk Write job via the `bulk_write_status()` method works.
+    fn bulk_write_status_success() {
         let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
         let api_domain = mockito::server_url();
-        let error_code = "INVALID_MODULE";
-        let body = format!(r#"{{
-            "code": "{}",
-            "details": {{}},
-            "message": "Please check if the URL trying to access is a correct one",
-            "status": "error"
-        }}"#, error_code);
-        let mocker = get_mocker("PUT", Matcher::Any, Some(&body));
-        let mut client = get_client(Some(String::from(access_token)), Some(String::from(api_domain)));
-
-        let mut record: HashMap<&str, &str> = HashMap::new();
-        record.insert("name", "New Record Name");
-
-        match client.update_many("INVALID_MODULE", vec![record]) {
-            Ok(_) => panic!("Response did not return an error"),
-            Err(err) => {
-                match err {
-                    ClientError::ApiError(error) => assert_eq!(error.code, error_code),
-                    _ => panic!("Wrong error type"),
+        let body = r#"{
+            "status": "COMPLETED",
+            "id": "554023000000568010",
+            "operation": "insert",
+            "created_by": {"id": "554023000000235011", "name": "Patricia Boyle"},
+            "created_time": "2019-05-09T14:01:24+05:30",
+            "resource": [
+                {
+                    "status": "COMPLETED",
+                    "type": "data",
+                    "module": "Leads",
+                    "file": {
+                        "status": "COMPLETED",
+                        "name": "records.csv",
+                        "added_count": 2,
+                        "skipped_count": 1,
+                        "updated_count": 0,
+                        "total_count": 3
+                    }
                 }
+            ],
+            "result": {
+                "download_url": "https://download-accl.zoho.com/v2/crm/4150868/bulk-write/554023000000568010/554023000000568010.zip"
             }
-        }
+        }"#;
+        let mocker = get_mocker("GET", "/crm/bulk/v2/write/554023000000568010", Some(body));
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        let job = client.bulk_write_status("554023000000568010").unwrap();
 
         mocker.assert();
+        assert!(job.is_finished());
+
+        let file = job.resource[0].file.as_ref().unwrap();
+        assert_eq!(file.added_count, 2);
+        assert_eq!(file.skipped_count, 1);
+        assert!(job.result.unwrap().download_url.ends_with("554023000000568010.zip"));
     }
 
     #[test]
-    /// Tests that a plain error message returned via the `update_many()` method returns an error.
-    fn update_many_text_error() {
+    /// Tests that an unknown Bulk Write job via the `bulk_write_status()` method returns an error.
+    fn bulk_write_status_error() {
         let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
         let api_domain = mockito::server_url();
-        let error_code = "invalid_client";
-        let body = format!("{}", error_code);
-        let mocker = get_mocker("PUT", Matcher::Any, Some(&body));
-        let mut client = get_client(Some(String::from(access_token)), Some(String::from(api_domain)));
-
-        let mut record: HashMap<&str, &str> = HashMap::new();
-        record.insert("name", "New Record Name");
-
-        match client.update_many("INVALID_MODULE", vec![record]) {
-            Ok(_) => panic!("Response did not return an error"),
-            Err(err) => {
-                assert_eq!(err.to_string(), error_code.to_string());
-            }
+        let body = r#"{"status":"error","code":"RESOURCE_NOT_FOUND","message":"The requested resource doesn't exist.","details":{}}"#;
+        let mocker = mock("GET", "/crm/bulk/v2/write/0")
+            .with_status(404)
+            .with_body(body)
+            .create();
+        let mut client = get_client(Some(access_token.to_string()), Some(api_domain));
+
+        match client.bulk_write_status("0") {
+            Err(ClientError::ApiError(error)) => assert_eq!(error.code, "RESOURCE_NOT_FOUND"),
+            _ => panic!("Wrong result"),
         }
 
         mocker.assert();
     }
 
     #[test]
-    fn test_parse_params() {
-        let mut params: HashMap<&str, &str> = HashMap::new();
-        params.insert("cvid", "00000");
-        params.insert("page", "2");
+    /// Tests that the access token is not sent to hosts outside of Zoho.
+    fn download_bulk_write_untrusted_host() {
+        let access_token = "9999.bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let mocker = mock("GET", "/result.zip")
+            .match_header("Authorization", Matcher::Missing)
+            .with_status(404)
+            .create();
+        let mut client = get_client(Some(access_token.to_string()), Some(String::from("https://www.zohoapis.com")));
 
-        let converted = parse_params(params).unwrap();
+        let url = format!("{}/result.zip", mockito::server_url());
 
-        match converted.as_str() {
-            "page=2&cvid=00000" => (),
-            "cvid=00000&page=2" => (),
-            _ => {
-                panic!("Params did not convert properly");
-            }
+        assert!(client.download_bulk_write(&url).is_err());
+        mocker.assert();
+    }
+
+    #[test]
+    fn zoho_hosts() {
+        assert!(is_zoho_host("www.zohoapis.com"));
+        assert!(is_zoho_host("download-accl.zoho.com"));
+        assert!(is_zoho_host("content.zohoapis.com.au"));
+        assert!(is_zoho_host("zoho.eu"));
+        assert!(!is_zoho_host("zoho.example.com"));
+        assert!(!is_zoho_host("www.zohoapis.com.example.com"));
+        assert!(!is_zoho_host("notzoho.com"));
+        assert!(!is_zoho_host("127.0.0.1"));
+    }
+
+    #[test]
+    fn content_domains() {
+        assert_eq!(content_domain("https://www.zohoapis.com").unwrap(), "https://content.zohoapis.com");
+        assert_eq!(content_domain("https://zohoapis.eu").unwrap(), "https://content.zohoapis.eu");
+        assert_eq!(content_domain("https://www.zohoapis.com.au/").unwrap(), "https://content.zohoapis.com.au");
+        assert!(content_domain("https://www.zoho.com").is_err());
+
+        match content_domain("https://crmsandbox.zoho.com") {
+            Err(ClientError::General(message)) => assert!(message.contains("sandbox")),
+            _ => panic!("Sandbox domain was accepted"),
         }
+
+        assert!(content_domain("https://www.zohoapis.example.com").is_err());
+        assert!(content_domain("http://127.0.0.1:1234").is_err());
     }
 }
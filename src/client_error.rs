@@ -13,15 +13,21 @@ pub enum ClientError {
 
     /// Error returned from most API requests.
     ApiError(ApiErrorResponse),
+
+    /// Error returned when request parameters are outside of the ranges Zoho accepts. The
+    /// request is not sent.
+    InvalidParams(String),
 }
 
 impl ClientError {
     /// Return the underlying error message as as string.
+    #[allow(clippy::inherent_to_string_shadow_display)]
     pub fn to_string(&self) -> String {
         match self {
             ClientError::General(error) => error.clone(),
             ClientError::UnexpectedResponseType(error) => error.clone(),
             ClientError::ApiError(error) => error.to_string(),
+            ClientError::InvalidParams(error) => error.clone(),
         }
     }
 }
@@ -31,7 +37,8 @@ impl fmt::Display for ClientError {
         match self {
             ClientError::General(error) => write!(f, "{}", error),
             ClientError::UnexpectedResponseType(error) => write!(f, "{}", error),
-            ClientError::ApiError(error) => write!(f, "{}", error.to_string()),
+            ClientError::ApiError(error) => write!(f, "{}", error),
+            ClientError::InvalidParams(error) => write!(f, "{}", error),
         }
     }
 }
@@ -54,6 +61,30 @@ impl From<serde_urlencoded::ser::Error> for ClientError {
     }
 }
 
+impl From<std::io::Error> for ClientError {
+    fn from(err: std::io::Error) -> Self {
+        ClientError::General(err.to_string())
+    }
+}
+
+impl From<reqwest::UrlError> for ClientError {
+    fn from(err: reqwest::UrlError) -> Self {
+        ClientError::General(err.to_string())
+    }
+}
+
+impl From<csv::Error> for ClientError {
+    fn from(err: csv::Error) -> Self {
+        ClientError::General(err.to_string())
+    }
+}
+
+impl From<zip::result::ZipError> for ClientError {
+    fn from(err: zip::result::ZipError) -> Self {
+        ClientError::General(err.to_string())
+    }
+}
+
 impl From<&str> for ClientError {
     fn from(err: &str) -> ClientError {
         ClientError::General(String::from(err))
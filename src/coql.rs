@@ -0,0 +1,404 @@
+//! Builder for COQL queries sent with the [`query()`](../struct.Client.html#method.query) method.
+//!
+//! You can read more about COQL here:
+//! [https://www.zoho.com/crm/developer/docs/api/COQL-Overview.html](https://www.zoho.com/crm/developer/docs/api/COQL-Overview.html)
+//!
+//! ### Example
+//!
+//! ```
+//! use zoho_crm::coql::{Condition, CoqlQuery, SortOrder};
+//!
+//! let query = CoqlQuery::select(&["Last_Name", "Account_Name.Account_Name"])
+//!     .from("Contacts")
+//!     .filter(Condition::eq("Last_Name", "O'Brien").and(Condition::gt("Annual_Revenue", 1000)))
+//!     .order_by("Last_Name", SortOrder::Asc)
+//!     .limit(10)
+//!     .offset(20);
+//!
+//! assert_eq!(
+//!     query.to_string(),
+//!     "select Last_Name, Account_Name.Account_Name from Contacts \
+//!      where (Last_Name = 'O\\'Brien' and Annual_Revenue > 1000) \
+//!      order by Last_Name asc limit 10 offset 20"
+//! );
+//! ```
+
+use crate::client_error::ClientError;
+pub use crate::params::SortOrder;
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+/// Default number of records Zoho returns when a query has no `limit` clause.
+const DEFAULT_LIMIT: usize = 200;
+
+/// A value compared against a field. Text values are quoted when rendered.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Text(String),
+    Number(Numeric),
+    Boolean(bool),
+    Null,
+}
+
+/// A number written into a query as is, such as `-12.50`.
+///
+/// Numbers are not quoted, so they can only be made from integers, finite floats, or text that
+/// is a plain decimal number.
+///
+/// ### Example
+///
+/// ```
+/// use zoho_crm::coql::{Condition, Numeric};
+///
+/// let amount: Numeric = "1000.50".parse().unwrap();
+///
+/// assert_eq!(Condition::gt("Amount", amount).to_string(), "Amount > 1000.50");
+/// assert!("1 or id != 0".parse::<Numeric>().is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Numeric(String);
+
+impl FromStr for Numeric {
+    type Err = ClientError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let digits = value.strip_prefix('-').unwrap_or(value);
+
+        let (whole, fraction) = match digits.find('.') {
+            Some(index) => (&digits[..index], Some(&digits[index + 1..])),
+            None => (digits, None),
+        };
+
+        let all_digits = |part: &str| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit());
+
+        if !all_digits(whole) || !fraction.is_none_or(all_digits) {
+            return Err(ClientError::InvalidParams(format!("{} is not a number", value)));
+        }
+
+        Ok(Numeric(value.to_string()))
+    }
+}
+
+impl fmt::Display for Numeric {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<Numeric> for Value {
+    fn from(value: Numeric) -> Self {
+        Value::Number(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::Text(value.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Value::Text(value)
+    }
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Value::Boolean(value)
+    }
+}
+
+impl From<i32> for Value {
+    fn from(value: i32) -> Self {
+        Value::Number(Numeric(value.to_string()))
+    }
+}
+
+impl From<i64> for Value {
+    fn from(value: i64) -> Self {
+        Value::Number(Numeric(value.to_string()))
+    }
+}
+
+impl From<u32> for Value {
+    fn from(value: u32) -> Self {
+        Value::Number(Numeric(value.to_string()))
+    }
+}
+
+impl From<u64> for Value {
+    fn from(value: u64) -> Self {
+        Value::Number(Numeric(value.to_string()))
+    }
+}
+
+/// NaN and infinite numbers cannot be written in a query, so they are rejected.
+impl TryFrom<f64> for Value {
+    type Error = ClientError;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        if !value.is_finite() {
+            return Err(ClientError::InvalidParams(format!("{} cannot be used in a query", value)));
+        }
+
+        Ok(Value::Number(Numeric(value.to_string())))
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Text(value) => write!(f, "'{}'", value.replace('\\', "\\\\").replace('\'', "\\'")),
+            Value::Number(value) => write!(f, "{}", value),
+            Value::Boolean(value) => write!(f, "{}", value),
+            Value::Null => write!(f, "null"),
+        }
+    }
+}
+
+/// A `where` clause expression.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Condition(String);
+
+impl Condition {
+    /// `field` is equal to `value`.
+    pub fn eq(field: &str, value: impl Into<Value>) -> Condition {
+        Condition::compare(field, "=", value.into())
+    }
+
+    /// `field` is not equal to `value`.
+    pub fn ne(field: &str, value: impl Into<Value>) -> Condition {
+        Condition::compare(field, "!=", value.into())
+    }
+
+    /// `field` is greater than `value`.
+    pub fn gt(field: &str, value: impl Into<Value>) -> Condition {
+        Condition::compare(field, ">", value.into())
+    }
+
+    /// `field` is greater than or equal to `value`.
+    pub fn ge(field: &str, value: impl Into<Value>) -> Condition {
+        Condition::compare(field, ">=", value.into())
+    }
+
+    /// `field` is less than `value`.
+    pub fn lt(field: &str, value: impl Into<Value>) -> Condition {
+        Condition::compare(field, "<", value.into())
+    }
+
+    /// `field` is less than or equal to `value`.
+    pub fn le(field: &str, value: impl Into<Value>) -> Condition {
+        Condition::compare(field, "<=", value.into())
+    }
+
+    /// `field` matches a pattern, where `%` matches any number of characters.
+    pub fn like(field: &str, pattern: &str) -> Condition {
+        Condition::compare(field, "like", pattern.into())
+    }
+
+    /// `field` is equal to any of `values`. This is the `in` operator.
+    pub fn one_of<I>(field: &str, values: I) -> Condition
+        where I: IntoIterator, I::Item: Into<Value>
+    {
+        let values: Vec<String> = values.into_iter().map(|value| value.into().to_string()).collect();
+
+        Condition(format!("{} in ({})", field, values.join(", ")))
+    }
+
+    /// Same as [`one_of()`](#method.one_of), named after the operator.
+    pub fn r#in<I>(field: &str, values: I) -> Condition
+        where I: IntoIterator, I::Item: Into<Value>
+    {
+        Condition::one_of(field, values)
+    }
+
+    /// `field` falls between `start` and `end`.
+    pub fn between(field: &str, start: impl Into<Value>, end: impl Into<Value>) -> Condition {
+        Condition(format!("{} between {} and {}", field, start.into(), end.into()))
+    }
+
+    /// `field` has no value.
+    pub fn is_null(field: &str) -> Condition {
+        Condition(format!("{} is null", field))
+    }
+
+    /// `field` has a value.
+    pub fn is_not_null(field: &str) -> Condition {
+        Condition(format!("{} is not null", field))
+    }
+
+    /// Combine this expression with another, where both must match.
+    pub fn and(self, other: Condition) -> Condition {
+        Condition(format!("({} and {})", self.0, other.0))
+    }
+
+    /// Combine this expression with another, where either must match.
+    pub fn or(self, other: Condition) -> Condition {
+        Condition(format!("({} or {})", self.0, other.0))
+    }
+
+    fn compare(field: &str, operator: &str, value: Value) -> Condition {
+        Condition(format!("{} {} {}", field, operator, value))
+    }
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A COQL `select` query.
+///
+/// Fields of looked up records can be selected and filtered with a dot, such as
+/// `Account_Name.Account_Name`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CoqlQuery {
+    fields: Vec<String>,
+    module: String,
+    condition: Option<Condition>,
+    order_by: Vec<(String, SortOrder)>,
+    limit: Option<usize>,
+    offset: Option<usize>,
+}
+
+impl CoqlQuery {
+    /// Start a query selecting the given fields.
+    pub fn select(fields: &[&str]) -> CoqlQuery {
+        CoqlQuery {
+            fields: fields.iter().map(|field| field.to_string()).collect(),
+            module: String::new(),
+            condition: None,
+            order_by: Vec::new(),
+            limit: None,
+            offset: None,
+        }
+    }
+
+    /// Set the module to select records from.
+    pub fn from(mut self, module: &str) -> CoqlQuery {
+        self.module = module.to_string();
+        self
+    }
+
+    /// Set the `where` clause.
+    pub fn filter(mut self, condition: Condition) -> CoqlQuery {
+        self.condition = Some(condition);
+        self
+    }
+
+    /// Add a field to sort by. Can be called multiple times to sort by several fields.
+    pub fn order_by(mut self, field: &str, order: SortOrder) -> CoqlQuery {
+        self.order_by.push((field.to_string(), order));
+        self
+    }
+
+    /// Set the maximum number of records to return.
+    pub fn limit(mut self, limit: usize) -> CoqlQuery {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Set the number of records to skip.
+    pub fn offset(mut self, offset: usize) -> CoqlQuery {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+impl fmt::Display for CoqlQuery {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "select {} from {}", self.fields.join(", "), self.module)?;
+
+        if let Some(condition) = &self.condition {
+            write!(f, " where {}", condition)?;
+        }
+
+        if !self.order_by.is_empty() {
+            let order_by: Vec<String> = self.order_by
+                .iter()
+                .map(|(field, order)| format!("{} {}", field, order))
+                .collect();
+
+            write!(f, " order by {}", order_by.join(", "))?;
+        }
+
+        // Zoho only accepts an offset alongside a limit
+        if self.limit.is_some() || self.offset.is_some() {
+            write!(f, " limit {}", self.limit.unwrap_or(DEFAULT_LIMIT))?;
+        }
+
+        if let Some(offset) = self.offset {
+            write!(f, " offset {}", offset)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn select_only() {
+        let query = CoqlQuery::select(&["Last_Name", "First_Name"]).from("Contacts");
+
+        assert_eq!(query.to_string(), "select Last_Name, First_Name from Contacts");
+    }
+
+    #[test]
+    fn quoted_values() {
+        let condition = Condition::eq("Last_Name", "O'Brien")
+            .or(Condition::like("Description", "C:\\%"));
+
+        assert_eq!(condition.to_string(), r"(Last_Name = 'O\'Brien' or Description like 'C:\\%')");
+    }
+
+    #[test]
+    fn list_values() {
+        let condition = Condition::one_of("Lead_Source", vec!["Web", "Trade Show"])
+            .and(Condition::between("Amount", 10, Value::try_from(20.5).unwrap()))
+            .and(Condition::is_not_null("Email"));
+
+        assert_eq!(
+            condition.to_string(),
+            "((Lead_Source in ('Web', 'Trade Show') and Amount between 10 and 20.5) and Email is not null)"
+        );
+    }
+
+    #[test]
+    fn offset_without_limit() {
+        let query = CoqlQuery::select(&["id"])
+            .from("Deals")
+            .filter(Condition::eq("Closed", true))
+            .order_by("Created_Time", SortOrder::Desc)
+            .order_by("id", SortOrder::Asc)
+            .offset(400);
+
+        assert_eq!(
+            query.to_string(),
+            "select id from Deals where Closed = true order by Created_Time desc, id asc limit 200 offset 400"
+        );
+    }
+
+    #[test]
+    fn non_finite_numbers() {
+        assert!(Value::try_from(f64::NAN).is_err());
+        assert!(Value::try_from(f64::INFINITY).is_err());
+        assert_eq!(Value::try_from(-0.5).unwrap().to_string(), "-0.5");
+    }
+
+    #[test]
+    fn parse_numeric() {
+        for value in &["0", "-12", "12.50", "-0.5"] {
+            assert_eq!(value.parse::<Numeric>().unwrap().to_string(), *value);
+        }
+
+        for value in &["", "-", "1.", ".5", "1e5", "1 or id != 0", "1)"] {
+            assert!(value.parse::<Numeric>().is_err(), "{} was accepted", value);
+        }
+    }
+}
@@ -0,0 +1,167 @@
+//! Builder for criteria expressions sent with the [`search()`](../struct.Client.html#method.search)
+//! method.
+
+use serde::{Serialize, Serializer};
+use std::fmt;
+
+/// A search criteria expression, such as `((Last_Name:equals:Burns)and(First_Name:starts_with:B))`.
+///
+/// Values are escaped when the expression is rendered, so they may safely contain parentheses
+/// and commas.
+///
+/// ### Example
+///
+/// ```
+/// use zoho_crm::Criteria;
+///
+/// let criteria = Criteria::equals("Last_Name", "Burns")
+///     .and(Criteria::starts_with("First_Name", "B"));
+///
+/// assert_eq!(criteria.to_string(), "((Last_Name:equals:Burns)and(First_Name:starts_with:B))");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum Criteria {
+    /// A single comparison of a field against one or more values.
+    Condition {
+        field: String,
+        comparator: String,
+        values: Vec<String>,
+    },
+
+    /// Both expressions must match.
+    And(Box<Criteria>, Box<Criteria>),
+
+    /// Either expression must match.
+    Or(Box<Criteria>, Box<Criteria>),
+}
+
+impl Criteria {
+    /// Match records where `field` is equal to `value`.
+    pub fn equals(field: &str, value: impl fmt::Display) -> Criteria {
+        Criteria::condition(field, "equals", vec![value.to_string()])
+    }
+
+    /// Match records where `field` starts with `value`.
+    pub fn starts_with(field: &str, value: impl fmt::Display) -> Criteria {
+        Criteria::condition(field, "starts_with", vec![value.to_string()])
+    }
+
+    /// Match records where `field` is equal to any of `values`. This is the `in` comparator.
+    pub fn one_of<I>(field: &str, values: I) -> Criteria
+        where I: IntoIterator, I::Item: fmt::Display
+    {
+        let values = values.into_iter().map(|value| value.to_string()).collect();
+
+        Criteria::condition(field, "in", values)
+    }
+
+    /// Same as [`one_of()`](#method.one_of), named after the comparator.
+    pub fn r#in<I>(field: &str, values: I) -> Criteria
+        where I: IntoIterator, I::Item: fmt::Display
+    {
+        Criteria::one_of(field, values)
+    }
+
+    /// Match records where `field` falls between `start` and `end`.
+    pub fn between(field: &str, start: impl fmt::Display, end: impl fmt::Display) -> Criteria {
+        Criteria::condition(field, "between", vec![start.to_string(), end.to_string()])
+    }
+
+    /// Combine this expression with another, where both must match.
+    pub fn and(self, other: Criteria) -> Criteria {
+        Criteria::And(Box::new(self), Box::new(other))
+    }
+
+    /// Combine this expression with another, where either must match.
+    pub fn or(self, other: Criteria) -> Criteria {
+        Criteria::Or(Box::new(self), Box::new(other))
+    }
+
+    fn condition(field: &str, comparator: &str, values: Vec<String>) -> Criteria {
+        Criteria::Condition {
+            field: field.to_string(),
+            comparator: comparator.to_string(),
+            values,
+        }
+    }
+}
+
+/// Escape the characters Zoho treats as special within a criteria value.
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        if let '\\' | '(' | ')' | ',' = c {
+            escaped.push('\\');
+        }
+
+        escaped.push(c);
+    }
+
+    escaped
+}
+
+impl fmt::Display for Criteria {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Criteria::Condition { field, comparator, values } => {
+                let values: Vec<String> = values.iter().map(|value| escape(value)).collect();
+
+                write!(f, "({}:{}:{})", field, comparator, values.join(","))
+            },
+            Criteria::And(left, right) => write!(f, "({}and{})", left, right),
+            Criteria::Or(left, right) => write!(f, "({}or{})", left, right),
+        }
+    }
+}
+
+impl Serialize for Criteria {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_condition() {
+        let criteria = Criteria::equals("Last_Name", "Burns");
+
+        assert_eq!(criteria.to_string(), "(Last_Name:equals:Burns)");
+    }
+
+    #[test]
+    fn nested_conditions() {
+        let criteria = Criteria::equals("Last_Name", "Burns")
+            .and(Criteria::starts_with("First_Name", "B"))
+            .or(Criteria::equals("Email", "burns@example.com"));
+
+        assert_eq!(
+            criteria.to_string(),
+            "(((Last_Name:equals:Burns)and(First_Name:starts_with:B))or(Email:equals:burns@example.com))"
+        );
+    }
+
+    #[test]
+    fn multiple_values() {
+        let criteria = Criteria::one_of("Lead_Source", vec!["Web", "Trade Show"])
+            .and(Criteria::between("Annual_Revenue", 1000, 5000));
+
+        assert_eq!(
+            criteria.to_string(),
+            "((Lead_Source:in:Web,Trade Show)and(Annual_Revenue:between:1000,5000))"
+        );
+    }
+
+    #[test]
+    fn escaped_values() {
+        let criteria = Criteria::one_of("Account_Name", vec!["Burns (Holdings), Inc", "C:\\Temp"]);
+
+        assert_eq!(
+            criteria.to_string(),
+            r"(Account_Name:in:Burns \(Holdings\)\, Inc,C:\\Temp)"
+        );
+    }
+}
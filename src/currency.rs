@@ -0,0 +1,422 @@
+//! Exact currency amounts, and types used with the currency methods of the
+//! [`Client`](../struct.Client.html).
+
+use crate::client_error::ClientError;
+use crate::response::Lookup;
+use serde::de::{self, Deserializer, Visitor};
+use serde::{Deserialize, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+/// Most decimal places a currency amount may have.
+const MAX_PRECISION: u32 = 18;
+
+/// An exact currency amount, stored as a whole number of its smallest unit, such as cents.
+///
+/// Zoho sends currency fields as JSON numbers, and exchange rates as strings. Both are
+/// accepted when deserializing. Amounts are serialized as JSON numbers.
+///
+/// Strings are read exactly. Formats such as JSON and CSV hand over decimal numbers as an
+/// `f64`, which is read through the shortest decimal that converts back to the same `f64`, so
+/// `1234.56` is read as `1234.56`. This is exact for amounts of up to 15 significant digits;
+/// read larger amounts as strings. Serializing goes through an `f64` the same way.
+///
+/// ### Example
+///
+/// ```
+/// use serde::Deserialize;
+/// use zoho_crm::Currency;
+///
+/// #[derive(Deserialize)]
+/// struct Deal {
+///     #[serde(rename = "Amount")]
+///     amount: Currency,
+/// }
+///
+/// let deal: Deal = serde_json::from_str(r#"{"Amount": 1234.56}"#).unwrap();
+/// let exchange_rate: Currency = "0.850000000".parse().unwrap();
+///
+/// assert_eq!(deal.amount.to_string(), "1234.56");
+/// assert_eq!(deal.amount.to_home(exchange_rate, 2).unwrap().to_string(), "1452.42");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Currency {
+    units: i128,
+    precision: u32,
+}
+
+impl Currency {
+    /// Create an amount of `units` of the smallest unit, with `precision` decimal places.
+    /// `Currency::new(1050, 2)` is `10.50`.
+    ///
+    /// `None` is returned if `precision` is more than 18.
+    pub fn new(units: i128, precision: u32) -> Option<Currency> {
+        if precision > MAX_PRECISION {
+            return None;
+        }
+
+        Some(Currency { units, precision })
+    }
+
+    /// Get the amount as a whole number of its smallest unit.
+    pub fn units(&self) -> i128 {
+        self.units
+    }
+
+    /// Get the number of decimal places of the amount.
+    pub fn precision(&self) -> u32 {
+        self.precision
+    }
+
+    /// Round the amount to a number of decimal places, rounding halves away from zero.
+    ///
+    /// `None` is returned if the amount is too large to hold that many decimal places.
+    pub fn with_precision(&self, precision: u32) -> Option<Currency> {
+        if precision > MAX_PRECISION {
+            return None;
+        }
+
+        rescale(self.units, self.precision, precision).map(|units| Currency { units, precision })
+    }
+
+    /// Convert an amount in another currency into the home currency, where `exchange_rate` is
+    /// the value of one unit of the home currency in the other currency.
+    ///
+    /// The result is rounded to `precision` decimal places. `None` is returned if the
+    /// exchange rate is zero, or the result is too large.
+    pub fn to_home(&self, exchange_rate: Currency, precision: u32) -> Option<Currency> {
+        if exchange_rate.units == 0 || precision > MAX_PRECISION {
+            return None;
+        }
+
+        let numerator = self.units.checked_mul(pow10(exchange_rate.precision + precision))?;
+        let denominator = exchange_rate.units.checked_mul(pow10(self.precision))?;
+
+        Some(Currency {
+            units: div_round(numerator, denominator),
+            precision,
+        })
+    }
+
+    /// Convert an amount in the home currency into another currency, where `exchange_rate`
+    /// is the value of one unit of the home currency in the other currency.
+    ///
+    /// The result is rounded to `precision` decimal places. `None` is returned if the result
+    /// is too large.
+    pub fn from_home(&self, exchange_rate: Currency, precision: u32) -> Option<Currency> {
+        if precision > MAX_PRECISION {
+            return None;
+        }
+
+        let units = self.units.checked_mul(exchange_rate.units)?;
+
+        rescale(units, self.precision + exchange_rate.precision, precision).map(|units| Currency { units, precision })
+    }
+}
+
+fn pow10(exponent: u32) -> i128 {
+    10i128.pow(exponent)
+}
+
+/// Divide, rounding halves away from zero.
+fn div_round(numerator: i128, denominator: i128) -> i128 {
+    let quotient = numerator / denominator;
+    let remainder = numerator % denominator;
+
+    if remainder.abs() * 2 >= denominator.abs() {
+        quotient + numerator.signum() * denominator.signum()
+    } else {
+        quotient
+    }
+}
+
+fn rescale(units: i128, from: u32, to: u32) -> Option<i128> {
+    if to >= from {
+        units.checked_mul(pow10(to - from))
+    } else {
+        Some(div_round(units, pow10(from - to)))
+    }
+}
+
+impl From<i64> for Currency {
+    fn from(value: i64) -> Self {
+        Currency {
+            units: i128::from(value),
+            precision: 0,
+        }
+    }
+}
+
+impl FromStr for Currency {
+    type Err = ClientError;
+
+    /// Parse an amount such as `-1234.50`. The precision is the number of decimal places
+    /// written.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid = || ClientError::General(format!("Invalid currency amount: {}", value));
+
+        let (negative, digits) = match value.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, value.strip_prefix('+').unwrap_or(value)),
+        };
+
+        let (whole, fraction) = match digits.find('.') {
+            Some(index) => (&digits[..index], &digits[index + 1..]),
+            None => (digits, ""),
+        };
+
+        let all_digits = |part: &str| part.chars().all(|c| c.is_ascii_digit());
+
+        if (whole.is_empty() && fraction.is_empty()) || !all_digits(whole) || !all_digits(fraction) {
+            return Err(invalid());
+        }
+
+        if fraction.len() > MAX_PRECISION as usize {
+            return Err(invalid());
+        }
+
+        let units: i128 = format!("{}{}", whole, fraction).parse().map_err(|_| invalid())?;
+
+        Ok(Currency {
+            units: if negative { -units } else { units },
+            precision: fraction.len() as u32,
+        })
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sign = if self.units < 0 { "-" } else { "" };
+        let units = self.units.unsigned_abs();
+
+        if self.precision == 0 {
+            return write!(f, "{}{}", sign, units);
+        }
+
+        let scale = 10u128.pow(self.precision);
+
+        write!(f, "{}{}.{:0width$}", sign, units / scale, units % scale, width = self.precision as usize)
+    }
+}
+
+/// Amounts are equal when they have the same value, regardless of precision, so `10.5` is
+/// equal to `10.50`.
+impl PartialEq for Currency {
+    fn eq(&self, other: &Currency) -> bool {
+        let precision = self.precision.max(other.precision);
+
+        rescale(self.units, self.precision, precision) == rescale(other.units, other.precision, precision)
+    }
+}
+
+impl Serialize for Currency {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let number: f64 = self.to_string().parse().map_err(serde::ser::Error::custom)?;
+
+        serializer.serialize_f64(number)
+    }
+}
+
+impl<'de> Deserialize<'de> for Currency {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(CurrencyVisitor)
+    }
+}
+
+struct CurrencyVisitor;
+
+impl<'de> Visitor<'de> for CurrencyVisitor {
+    type Value = Currency;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a currency amount, as a number or string")
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Currency, E> {
+        Ok(Currency::from(value))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Currency, E> {
+        Ok(Currency {
+            units: i128::from(value),
+            precision: 0,
+        })
+    }
+
+    /// Read through the shortest decimal that converts back to `value`.
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Currency, E> {
+        if !value.is_finite() {
+            return Err(E::custom(format!("Invalid currency amount: {}", value)));
+        }
+
+        self.visit_str(&format!("{}", value))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Currency, E> {
+        value.trim().parse().map_err(E::custom)
+    }
+}
+
+/// A currency enabled for your organization.
+#[derive(Debug, Deserialize)]
+pub struct OrgCurrency {
+    pub id: String,
+
+    /// Such as `US Dollar - USD`.
+    pub name: String,
+
+    /// Such as `USD`.
+    pub iso_code: String,
+
+    pub symbol: Option<String>,
+
+    /// Value of one unit of the home currency in this currency.
+    pub exchange_rate: Option<Currency>,
+
+    /// Whether this is the home currency of the organization.
+    #[serde(default)]
+    pub is_base: bool,
+
+    #[serde(default)]
+    pub is_active: bool,
+
+    /// Whether the symbol is written before the amount.
+    #[serde(default)]
+    pub prefix_symbol: bool,
+
+    pub format: Option<CurrencyFormat>,
+
+    pub created_by: Option<Lookup>,
+    pub modified_by: Option<Lookup>,
+    pub created_time: Option<String>,
+    pub modified_time: Option<String>,
+}
+
+/// How amounts of a currency are written.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CurrencyFormat {
+    /// Either `Period`, `Comma` or `Space`.
+    pub decimal_separator: String,
+
+    /// Either `Period`, `Comma` or `Space`.
+    pub thousand_separator: String,
+
+    /// Either `0`, `2` or `3`.
+    pub decimal_places: String,
+}
+
+/// Data sent when adding or updating a currency. Any field left as `None` is not sent.
+///
+/// `id` is required when updating currencies, while `name`, `iso_code` and `exchange_rate`
+/// are required when adding them.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CurrencyData {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iso_code: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+
+    /// Value of one unit of the home currency in this currency.
+    #[serde(serialize_with = "serialize_exchange_rate", skip_serializing_if = "Option::is_none")]
+    pub exchange_rate: Option<Currency>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefix_symbol: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<CurrencyFormat>,
+}
+
+/// Zoho expects exchange rates as strings.
+fn serialize_exchange_rate<S: Serializer>(exchange_rate: &Option<Currency>, serializer: S) -> Result<S::Ok, S::Error> {
+    match exchange_rate {
+        Some(exchange_rate) => serializer.collect_str(exchange_rate),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Response sent back from the [`currencies()`](../struct.Client.html#method.currencies)
+/// method, and when adding or updating currencies.
+#[derive(Debug, Deserialize)]
+pub(crate) struct CurrenciesResponse<T> {
+    pub currencies: Vec<T>,
+}
+
+/// Response sent back when enabling multiple currencies.
+#[derive(Debug, Deserialize)]
+pub(crate) struct BaseCurrencyResponse {
+    pub base_currency: crate::response::ApiActionResponseItem,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_display() {
+        for value in &["0", "10.50", "-0.05", "1234567890123.123456789"] {
+            assert_eq!(value.parse::<Currency>().unwrap().to_string(), *value);
+        }
+
+        assert_eq!("+.5".parse::<Currency>().unwrap().to_string(), "0.5");
+        assert!("1.2.3".parse::<Currency>().is_err());
+        assert!("".parse::<Currency>().is_err());
+        assert!("1e5".parse::<Currency>().is_err());
+    }
+
+    #[test]
+    fn deserialize_number_or_string() {
+        let amounts: Vec<Currency> = serde_json::from_str(r#"[1234.56, 0.1, 100, "20.000000000", "12345678901234567.89"]"#).unwrap();
+
+        assert_eq!(amounts[0].to_string(), "1234.56");
+        assert_eq!(amounts[1].to_string(), "0.1");
+        assert_eq!(amounts[2].to_string(), "100");
+        assert_eq!(amounts[3].precision(), 9);
+        assert_eq!(amounts[4].to_string(), "12345678901234567.89");
+        assert_eq!(serde_json::from_str::<Currency>("1e5").unwrap().to_string(), "100000");
+        assert!(serde_json::from_str::<Currency>(r#""1e5""#).is_err());
+    }
+
+    #[test]
+    fn serialize_number() {
+        let amount = Currency::new(123456, 2).unwrap();
+        let tenth = Currency::new(1, 1).unwrap();
+
+        assert_eq!(serde_json::to_string(&amount).unwrap(), "1234.56");
+        assert_eq!(serde_json::to_string(&tenth).unwrap(), "0.1");
+        assert_eq!(serde_json::from_value::<Currency>(serde_json::to_value(amount).unwrap()).unwrap(), amount);
+    }
+
+    #[test]
+    fn equal_regardless_of_precision() {
+        assert_eq!(Currency::new(105, 1), Currency::new(1050, 2));
+        assert_ne!(Currency::new(105, 1), Currency::new(1051, 2));
+        assert!(Currency::new(1, 19).is_none());
+    }
+
+    #[test]
+    fn round_half_away_from_zero() {
+        let round = |units, precision| Currency::new(units, precision).unwrap().with_precision(2).unwrap().to_string();
+
+        assert_eq!(round(1005, 3), "1.01");
+        assert_eq!(round(-1005, 3), "-1.01");
+        assert_eq!(round(1004, 3), "1.00");
+    }
+
+    #[test]
+    fn convert_with_exchange_rate() {
+        let rate: Currency = "0.850000000".parse().unwrap();
+        let amount: Currency = "100.00".parse().unwrap();
+
+        assert_eq!(amount.to_home(rate, 2).unwrap().to_string(), "117.65");
+        assert_eq!(amount.from_home(rate, 2).unwrap().to_string(), "85.00");
+        assert!(amount.to_home(Currency::new(0, 2).unwrap(), 2).is_none());
+    }
+}
@@ -0,0 +1,92 @@
+use serde::de::{self, Deserializer, Visitor};
+use serde::forward_to_deserialize_any;
+
+/// Get the API names of the fields a type deserializes from.
+///
+/// This reads the field names `serde` generates for a struct, so any `rename` or
+/// `rename_all` attributes are taken into account. It can be used to only fetch the fields a
+/// type needs, with [`get_with_fields()`](struct.Client.html#method.get_with_fields) or
+/// [`ListParams::for_type()`](struct.ListParams.html#method.for_type).
+///
+/// An empty list is returned for types that are not plain structs, including structs with
+/// flattened fields.
+///
+/// ### Example
+///
+/// ```
+/// use serde::Deserialize;
+/// use zoho_crm::field_names;
+///
+/// #[derive(Deserialize)]
+/// struct Account {
+///     id: String,
+///
+///     #[serde(rename = "Account_Name")]
+///     name: String,
+/// }
+///
+/// assert_eq!(field_names::<Account>(), &["id", "Account_Name"]);
+/// ```
+pub fn field_names<T: serde::de::DeserializeOwned>() -> &'static [&'static str] {
+    let mut fields = None;
+
+    // the deserializer always fails, after recording the struct's fields
+    let _ = T::deserialize(FieldNamesDeserializer { fields: &mut fields });
+
+    fields.unwrap_or(&[])
+}
+
+/// Deserializer that only records the field names of the struct it is asked to deserialize.
+struct FieldNamesDeserializer<'a> {
+    fields: &'a mut Option<&'static [&'static str]>,
+}
+
+impl<'de, 'a> Deserializer<'de> for FieldNamesDeserializer<'a> {
+    type Error = de::value::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Self::Error> {
+        Err(de::Error::custom("not a struct"))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        _visitor: V
+    ) -> Result<V::Value, Self::Error> {
+        *self.fields = Some(fields);
+
+        Err(de::Error::custom("field names recorded"))
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+        option unit unit_struct newtype_struct seq tuple tuple_struct map enum identifier
+        ignored_any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use std::collections::HashMap;
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "PascalCase")]
+    #[allow(dead_code)]
+    struct Contact {
+        last_name: String,
+        email: Option<String>,
+    }
+
+    #[test]
+    fn renamed_fields() {
+        assert_eq!(field_names::<Contact>(), &["LastName", "Email"]);
+    }
+
+    #[test]
+    fn not_a_struct() {
+        assert!(field_names::<HashMap<String, String>>().is_empty());
+    }
+}
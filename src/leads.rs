@@ -0,0 +1,81 @@
+//! Types used with the [`convert_lead()`](../struct.Client.html#method.convert_lead) method.
+
+use serde::{Deserialize, Serialize};
+
+/// Options for converting a lead. Any option left as `None` is not sent, and Zoho's default
+/// is used.
+///
+/// ### Example
+///
+/// ```
+/// use serde_json::json;
+/// use zoho_crm::leads::ConvertLeadOptions;
+///
+/// let options = ConvertLeadOptions {
+///     notify_lead_owner: Some(true),
+///     account_id: Some(String::from("EXISTING_ACCOUNT_ID")),
+///     deal: Some(json!({
+///         "Deal_Name": "Robert",
+///         "Closing_Date": "2019-12-24",
+///         "Stage": "Closed Won",
+///         "Amount": 56.6,
+///     })),
+///     ..ConvertLeadOptions::default()
+/// };
+/// ```
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ConvertLeadOptions {
+    /// Overwrite the details of an existing account or contact with the details of the lead.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overwrite: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notify_lead_owner: Option<bool>,
+
+    /// Notify the owner of the new account, contact and deal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notify_new_entity_owner: Option<bool>,
+
+    /// ID of the user to assign the new records to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assign_to: Option<String>,
+
+    /// ID of an existing account to attach the lead to, instead of creating a new one.
+    #[serde(rename = "Accounts", skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<String>,
+
+    /// ID of an existing contact to attach the lead to, instead of creating a new one.
+    #[serde(rename = "Contacts", skip_serializing_if = "Option::is_none")]
+    pub contact_id: Option<String>,
+
+    /// Fields of a deal to create along with the conversion. No deal is created when this is
+    /// `None`.
+    #[serde(rename = "Deals", skip_serializing_if = "Option::is_none")]
+    pub deal: Option<serde_json::Value>,
+}
+
+/// IDs of the records a lead was converted into.
+#[derive(Debug, Deserialize)]
+pub struct ConvertedLead {
+    #[serde(rename = "Accounts")]
+    pub account_id: Option<String>,
+
+    #[serde(rename = "Contacts")]
+    pub contact_id: Option<String>,
+
+    /// Only set when a deal was created along with the conversion.
+    #[serde(rename = "Deals")]
+    pub deal_id: Option<String>,
+}
+
+/// Item sent back when converting a lead. On error, Zoho sends a `code`, `message` and
+/// `status` in place of the record IDs.
+#[derive(Debug, Deserialize)]
+pub(crate) struct ConvertLeadResponseItem {
+    #[serde(flatten)]
+    pub converted: ConvertedLead,
+
+    pub code: Option<String>,
+    pub message: Option<String>,
+    pub status: Option<String>,
+}
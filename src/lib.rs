@@ -44,12 +44,36 @@ extern crate serde;
 extern crate serde_json;
 extern crate serde_urlencoded;
 
+pub mod attachments;
+pub mod bulk_read;
+pub mod bulk_write;
 mod client_error;
 mod client;
+pub mod coql;
+pub mod criteria;
+pub mod currency;
+mod fields;
+pub mod leads;
+pub mod mass_update;
+pub mod metadata;
+pub mod notes;
+pub mod org;
+mod pagination;
+mod params;
 pub mod response;
+pub mod search;
+pub mod tags;
 mod token_record;
+pub mod users;
 
 pub use client::Client;
+#[allow(deprecated)]
 pub use client::parse_params;
 pub use client_error::ClientError;
+pub use criteria::Criteria;
+pub use currency::Currency;
+pub use fields::field_names;
+pub use pagination::{RecordIter, RecordStream};
+pub use params::{DeletedParams, DeletedType, FlagFilter, ListParams, SortOrder};
+pub use search::{CountFilter, SearchParams, SearchQuery};
 pub use token_record::TokenRecord;
@@ -0,0 +1,96 @@
+//! Types used with the [`mass_update()`](../struct.Client.html#method.mass_update) method.
+
+use serde::{Deserialize, Serialize};
+
+/// The records a mass update applies to.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MassUpdateTarget {
+    /// Records with the given IDs, up to 50,000.
+    Ids(Vec<String>),
+
+    /// Records in the custom view with the given ID.
+    Cvid(String),
+}
+
+/// Body sent to start a mass update job.
+#[derive(Debug, Serialize)]
+pub(crate) struct MassUpdateRequest<T> {
+    pub data: Vec<T>,
+
+    #[serde(flatten)]
+    pub target: MassUpdateTarget,
+}
+
+/// Response sent back when a mass update job is scheduled.
+#[derive(Debug, Deserialize)]
+pub(crate) struct MassUpdateResponse {
+    pub data: Vec<MassUpdateResponseItem>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct MassUpdateResponseItem {
+    pub code: String,
+    pub details: MassUpdateResponseDetails,
+    pub message: String,
+    pub status: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct MassUpdateResponseDetails {
+    pub job_id: Option<String>,
+}
+
+/// Progress of a mass update job, returned by the
+/// [`mass_update_status()`](../struct.Client.html#method.mass_update_status) method.
+#[derive(Debug, Deserialize)]
+pub struct MassUpdateStatus {
+    /// State of the job, such as `SCHEDULED`, `RUNNING` or `COMPLETED`.
+    #[serde(rename = "Status")]
+    pub status: String,
+
+    #[serde(rename = "Total_Count", default)]
+    pub total_count: usize,
+
+    #[serde(rename = "Updated_Count", default)]
+    pub updated_count: usize,
+
+    #[serde(rename = "Not_Updated_Count", default)]
+    pub not_updated_count: usize,
+
+    #[serde(rename = "Failed_Count", default)]
+    pub failed_count: usize,
+}
+
+impl MassUpdateStatus {
+    /// Whether the job has stopped running, either because it completed or failed.
+    pub fn is_finished(&self) -> bool {
+        self.status == "COMPLETED" || self.status == "FAILED"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_target() {
+        let by_ids = MassUpdateRequest {
+            data: vec![serde_json::json!({"Lead_Status": "Contacted"})],
+            target: MassUpdateTarget::Ids(vec![String::from("100"), String::from("101")]),
+        };
+        let by_view = MassUpdateRequest {
+            data: vec![serde_json::json!({"Lead_Status": "Contacted"})],
+            target: MassUpdateTarget::Cvid(String::from("200")),
+        };
+
+        assert_eq!(
+            serde_json::to_string(&by_ids).unwrap(),
+            r#"{"data":[{"Lead_Status":"Contacted"}],"ids":["100","101"]}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&by_view).unwrap(),
+            r#"{"data":[{"Lead_Status":"Contacted"}],"cvid":"200"}"#
+        );
+    }
+}
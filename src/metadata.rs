@@ -0,0 +1,414 @@
+//! Types used with the metadata methods of the [`Client`](../struct.Client.html), which
+//! describe the modules of your Zoho account.
+
+use crate::response::Lookup;
+use serde::{Deserialize, Deserializer};
+use std::fmt;
+
+/// A module of your Zoho account, including custom modules.
+#[derive(Debug, Deserialize)]
+pub struct ModuleMeta {
+    pub id: String,
+
+    /// Name used to refer to the module in API calls, such as `Leads`.
+    pub api_name: String,
+
+    pub module_name: String,
+    pub singular_label: String,
+    pub plural_label: String,
+
+    /// Either `default` for built-in modules, or `custom`.
+    pub generated_type: Option<String>,
+
+    /// Whether records of the module can be accessed through the API.
+    #[serde(default)]
+    pub api_supported: bool,
+
+    #[serde(default)]
+    pub creatable: bool,
+
+    #[serde(default)]
+    pub editable: bool,
+
+    #[serde(default)]
+    pub deletable: bool,
+
+    #[serde(default)]
+    pub viewable: bool,
+
+    #[serde(default)]
+    pub convertable: bool,
+
+    #[serde(default)]
+    pub visible: bool,
+
+    /// Profiles that have access to the module.
+    #[serde(default)]
+    pub profiles: Vec<Lookup>,
+
+    pub modified_by: Option<Lookup>,
+    pub modified_time: Option<String>,
+}
+
+/// Response sent back from the metadata methods that fetch modules.
+#[derive(Debug, Deserialize)]
+pub(crate) struct ModulesResponse {
+    pub modules: Vec<ModuleMeta>,
+}
+
+/// A field of a module, returned by the [`fields()`](../struct.Client.html#method.fields)
+/// method.
+#[derive(Debug, Deserialize)]
+pub struct FieldMeta {
+    pub id: String,
+
+    /// Name used to refer to the field in records, such as `Last_Name`.
+    pub api_name: String,
+
+    pub field_label: Option<String>,
+    pub data_type: DataType,
+
+    /// Most characters the field accepts.
+    pub length: Option<usize>,
+
+    /// Whether every record must have a value for the field, regardless of layout.
+    #[serde(rename = "system_mandatory", default)]
+    pub mandatory: bool,
+
+    #[serde(default)]
+    pub read_only: bool,
+
+    #[serde(default)]
+    pub custom_field: bool,
+
+    /// Values allowed by `picklist` and `multiselectpicklist` fields.
+    #[serde(default)]
+    pub pick_list_values: Vec<PickListValue>,
+
+    /// The module looked up by `lookup` fields. Zoho sends an empty object for other fields.
+    pub lookup: Option<FieldLookup>,
+}
+
+impl FieldMeta {
+    /// Get the API name of the module this field looks up, if it is a lookup field.
+    pub fn lookup_module(&self) -> Option<&str> {
+        self.lookup.as_ref().and_then(|lookup| lookup.module.as_deref())
+    }
+}
+
+/// A value allowed by a picklist field.
+#[derive(Debug, Deserialize)]
+pub struct PickListValue {
+    /// Label shown in Zoho.
+    pub display_value: String,
+
+    /// Value sent and received through the API.
+    pub actual_value: String,
+
+    pub sequence_number: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FieldLookup {
+    pub id: Option<String>,
+    pub api_name: Option<String>,
+    pub display_label: Option<String>,
+    pub module: Option<String>,
+}
+
+/// Type of the value a field holds.
+///
+/// Types this crate does not know about are kept as `Other`, so new Zoho types do not cause
+/// errors.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(from = "String")]
+pub enum DataType {
+    Text,
+    TextArea,
+    Email,
+    Phone,
+    Website,
+    Picklist,
+    MultiSelectPicklist,
+    Date,
+    DateTime,
+    Integer,
+    BigInt,
+    Double,
+    Decimal,
+    Currency,
+    Percent,
+    Boolean,
+    Lookup,
+    OwnerLookup,
+    UserLookup,
+    MultiSelectLookup,
+    AutoNumber,
+    Formula,
+    FileUpload,
+    ImageUpload,
+    Subform,
+    Other(String),
+}
+
+impl DataType {
+    /// Get the name Zoho uses for the type, such as `multiselectpicklist`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            DataType::Text => "text",
+            DataType::TextArea => "textarea",
+            DataType::Email => "email",
+            DataType::Phone => "phone",
+            DataType::Website => "website",
+            DataType::Picklist => "picklist",
+            DataType::MultiSelectPicklist => "multiselectpicklist",
+            DataType::Date => "date",
+            DataType::DateTime => "datetime",
+            DataType::Integer => "integer",
+            DataType::BigInt => "bigint",
+            DataType::Double => "double",
+            DataType::Decimal => "decimal",
+            DataType::Currency => "currency",
+            DataType::Percent => "percent",
+            DataType::Boolean => "boolean",
+            DataType::Lookup => "lookup",
+            DataType::OwnerLookup => "ownerlookup",
+            DataType::UserLookup => "userlookup",
+            DataType::MultiSelectLookup => "multiselectlookup",
+            DataType::AutoNumber => "autonumber",
+            DataType::Formula => "formula",
+            DataType::FileUpload => "fileupload",
+            DataType::ImageUpload => "imageupload",
+            DataType::Subform => "subform",
+            DataType::Other(data_type) => data_type,
+        }
+    }
+}
+
+impl From<&str> for DataType {
+    fn from(data_type: &str) -> Self {
+        match data_type.to_lowercase().as_str() {
+            "text" => DataType::Text,
+            "textarea" => DataType::TextArea,
+            "email" => DataType::Email,
+            "phone" => DataType::Phone,
+            "website" => DataType::Website,
+            "picklist" => DataType::Picklist,
+            "multiselectpicklist" => DataType::MultiSelectPicklist,
+            "date" => DataType::Date,
+            "datetime" => DataType::DateTime,
+            "integer" => DataType::Integer,
+            "bigint" => DataType::BigInt,
+            "double" => DataType::Double,
+            "decimal" => DataType::Decimal,
+            "currency" => DataType::Currency,
+            "percent" => DataType::Percent,
+            "boolean" => DataType::Boolean,
+            "lookup" => DataType::Lookup,
+            "ownerlookup" => DataType::OwnerLookup,
+            "userlookup" => DataType::UserLookup,
+            "multiselectlookup" => DataType::MultiSelectLookup,
+            "autonumber" => DataType::AutoNumber,
+            "formula" => DataType::Formula,
+            "fileupload" => DataType::FileUpload,
+            "imageupload" => DataType::ImageUpload,
+            "subform" => DataType::Subform,
+            _ => DataType::Other(data_type.to_string()),
+        }
+    }
+}
+
+impl From<String> for DataType {
+    fn from(data_type: String) -> Self {
+        DataType::from(data_type.as_str())
+    }
+}
+
+impl fmt::Display for DataType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Response sent back from the [`fields()`](../struct.Client.html#method.fields) method.
+#[derive(Debug, Deserialize)]
+pub(crate) struct FieldsResponse {
+    pub fields: Vec<FieldMeta>,
+}
+
+/// A layout of a module, which arranges its fields into sections.
+#[derive(Debug, Deserialize)]
+pub struct Layout {
+    pub id: String,
+    pub name: String,
+
+    #[serde(default)]
+    pub visible: bool,
+
+    /// Profiles that use the layout.
+    #[serde(default)]
+    pub profiles: Vec<Lookup>,
+
+    #[serde(default)]
+    pub sections: Vec<LayoutSection>,
+
+    pub created_time: Option<String>,
+    pub modified_time: Option<String>,
+}
+
+/// A section of a layout.
+#[derive(Debug, Deserialize)]
+pub struct LayoutSection {
+    pub name: String,
+    pub display_label: Option<String>,
+    pub sequence_number: Option<usize>,
+
+    #[serde(default)]
+    pub fields: Vec<FieldMeta>,
+}
+
+/// Response sent back from the [`layouts()`](../struct.Client.html#method.layouts) method.
+#[derive(Debug, Deserialize)]
+pub(crate) struct LayoutsResponse {
+    pub layouts: Vec<Layout>,
+}
+
+/// A custom view of a module. Its `id` can be used as the `cvid` of
+/// [`ListParams`](../struct.ListParams.html).
+#[derive(Debug, Deserialize)]
+pub struct CustomView {
+    pub id: String,
+    pub name: String,
+    pub display_value: String,
+
+    /// Name of built-in views, such as `ALLVIEWS`.
+    pub system_name: Option<String>,
+
+    /// Such as `public_views`, `shared_with_me` or `created_by_me`.
+    pub category: Option<String>,
+
+    /// Whether the view is shown by default.
+    #[serde(default)]
+    pub default: bool,
+
+    /// API names of the fields shown in the view. Only sent by the
+    /// [`custom_view()`](../struct.Client.html#method.custom_view) method.
+    #[serde(default, deserialize_with = "deserialize_field_names")]
+    pub fields: Vec<String>,
+
+    /// Records shown in the view. Only sent by the
+    /// [`custom_view()`](../struct.Client.html#method.custom_view) method.
+    pub criteria: Option<ViewCriteria>,
+
+    pub sort_by: Option<String>,
+    pub sort_order: Option<String>,
+
+    pub created_time: Option<String>,
+    pub modified_time: Option<String>,
+}
+
+/// Filter of the records shown in a custom view.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum ViewCriteria {
+    /// Several filters, combined with `group_operator`, either `and` or `or`.
+    Group {
+        group_operator: String,
+        group: Vec<ViewCriteria>,
+    },
+
+    /// A single comparison of a field against a value.
+    Condition {
+        #[serde(deserialize_with = "deserialize_field_name")]
+        field: String,
+
+        /// Such as `equal`, `contains` or `starts_with`.
+        comparator: String,
+
+        value: serde_json::Value,
+    },
+}
+
+/// Response sent back from the [`custom_views()`](../struct.Client.html#method.custom_views)
+/// and [`custom_view()`](../struct.Client.html#method.custom_view) methods.
+#[derive(Debug, Deserialize)]
+pub(crate) struct CustomViewsResponse {
+    pub custom_views: Vec<CustomView>,
+}
+
+/// Zoho refers to fields of custom views either by API name, or by an object holding the
+/// API name and ID of the field.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum FieldName {
+    Name(String),
+    Field { api_name: String },
+}
+
+impl From<FieldName> for String {
+    fn from(field: FieldName) -> Self {
+        match field {
+            FieldName::Name(api_name) => api_name,
+            FieldName::Field { api_name } => api_name,
+        }
+    }
+}
+
+fn deserialize_field_name<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    FieldName::deserialize(deserializer).map(String::from)
+}
+
+fn deserialize_field_names<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+    let fields: Vec<FieldName> = Vec::deserialize(deserializer)?;
+
+    Ok(fields.into_iter().map(String::from).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_data_type() {
+        let data_type: DataType = serde_json::from_str(r#""multiselectpicklist""#).unwrap();
+
+        assert_eq!(data_type, DataType::MultiSelectPicklist);
+        assert_eq!(data_type.to_string(), "multiselectpicklist");
+    }
+
+    #[test]
+    fn unknown_data_type() {
+        let data_type: DataType = serde_json::from_str(r#""event_reminder""#).unwrap();
+
+        assert_eq!(data_type, DataType::Other(String::from("event_reminder")));
+        assert_eq!(data_type.as_str(), "event_reminder");
+    }
+
+    #[test]
+    fn view_criteria() {
+        let criteria: ViewCriteria = serde_json::from_str(r#"{
+            "group_operator": "and",
+            "group": [
+                {"comparator": "equal", "field": {"api_name": "Owner", "id": "100"}, "value": "${CURRENTUSER}"},
+                {"comparator": "equal", "field": "Lead_Status", "value": ["New", "Contacted"]}
+            ]
+        }"#).unwrap();
+
+        match criteria {
+            ViewCriteria::Group { group_operator, group } => {
+                assert_eq!(group_operator, "and");
+
+                match &group[0] {
+                    ViewCriteria::Condition { field, .. } => assert_eq!(field, "Owner"),
+                    _ => panic!("expected a condition"),
+                }
+
+                match &group[1] {
+                    ViewCriteria::Condition { field, .. } => assert_eq!(field, "Lead_Status"),
+                    _ => panic!("expected a condition"),
+                }
+            },
+            _ => panic!("expected a group"),
+        }
+    }
+}
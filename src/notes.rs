@@ -0,0 +1,60 @@
+//! Types used with the notes methods of the [`Client`](../struct.Client.html).
+
+use crate::response::Lookup;
+use serde::{Deserialize, Serialize};
+
+/// A note attached to a record.
+#[derive(Debug, Deserialize)]
+pub struct Note {
+    pub id: String,
+
+    #[serde(rename = "Note_Title")]
+    pub note_title: Option<String>,
+
+    #[serde(rename = "Note_Content")]
+    pub note_content: Option<String>,
+
+    /// The record the note is attached to.
+    #[serde(rename = "Parent_Id")]
+    pub parent_id: Option<Lookup>,
+
+    /// API name of the module of the record the note is attached to.
+    #[serde(rename = "$se_module")]
+    pub se_module: Option<String>,
+
+    #[serde(rename = "Owner")]
+    pub owner: Option<Lookup>,
+
+    #[serde(rename = "Created_By")]
+    pub created_by: Option<Lookup>,
+
+    #[serde(rename = "Modified_By")]
+    pub modified_by: Option<Lookup>,
+
+    #[serde(rename = "Created_Time")]
+    pub created_time: Option<String>,
+
+    #[serde(rename = "Modified_Time")]
+    pub modified_time: Option<String>,
+}
+
+/// Data sent when creating or updating a note. Any field left as `None` is not sent.
+///
+/// `parent_id` and `se_module` are only needed when creating notes outside of a specific
+/// record, with the [`create_notes()`](../struct.Client.html#method.create_notes) method.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct NoteData {
+    #[serde(rename = "Note_Title", skip_serializing_if = "Option::is_none")]
+    pub note_title: Option<String>,
+
+    #[serde(rename = "Note_Content", skip_serializing_if = "Option::is_none")]
+    pub note_content: Option<String>,
+
+    /// ID of the record to attach the note to.
+    #[serde(rename = "Parent_Id", skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+
+    /// API name of the module of the record to attach the note to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub se_module: Option<String>,
+}
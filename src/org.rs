@@ -0,0 +1,122 @@
+//! Types used with the organization methods of the [`Client`](../struct.Client.html).
+
+use crate::response::Lookup;
+use serde::Deserialize;
+
+/// Details of your Zoho organization.
+#[derive(Debug, Deserialize)]
+pub struct Org {
+    pub id: String,
+    pub company_name: Option<String>,
+    pub alias: Option<String>,
+    pub domain_name: Option<String>,
+    pub primary_email: Option<String>,
+    pub phone: Option<String>,
+    pub website: Option<String>,
+
+    /// Such as `Asia/Kolkata`.
+    pub time_zone: Option<String>,
+
+    /// Name of the home currency, such as `US Dollar - USD`.
+    pub currency: Option<String>,
+
+    pub currency_symbol: Option<String>,
+    pub currency_locale: Option<String>,
+
+    /// ISO code of the home currency, such as `USD`.
+    pub iso_code: Option<String>,
+
+    /// Whether multiple currencies are enabled.
+    #[serde(default)]
+    pub mc_status: bool,
+
+    pub street: Option<String>,
+    pub city: Option<String>,
+    pub state: Option<String>,
+    pub zip: Option<String>,
+    pub country: Option<String>,
+    pub country_code: Option<String>,
+
+    pub primary_zuid: Option<String>,
+    pub zgid: Option<String>,
+}
+
+/// A role users can be given, which determines the records they can see.
+#[derive(Debug, Deserialize)]
+pub struct Role {
+    pub id: String,
+    pub name: String,
+    pub display_label: Option<String>,
+    pub description: Option<String>,
+
+    /// The role above this one in the hierarchy.
+    pub reporting_to: Option<Lookup>,
+
+    /// Whether users with the role can see the records of other users with the same role.
+    #[serde(default)]
+    pub share_with_peers: bool,
+
+    #[serde(default)]
+    pub admin_user: bool,
+}
+
+/// A profile users can be given, which determines the actions they can take.
+#[derive(Debug, Deserialize)]
+pub struct Profile {
+    pub id: String,
+    pub name: String,
+    pub display_label: Option<String>,
+    pub description: Option<String>,
+
+    /// Whether the profile was created by your organization.
+    #[serde(default)]
+    pub custom: bool,
+
+    pub created_by: Option<Lookup>,
+    pub modified_by: Option<Lookup>,
+    pub created_time: Option<String>,
+    pub modified_time: Option<String>,
+}
+
+/// A territory of your organization. Its `id` can be used as the `territory_id` of
+/// [`ListParams`](../struct.ListParams.html).
+#[derive(Debug, Deserialize)]
+pub struct Territory {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+
+    /// ID of the territory above this one in the hierarchy.
+    pub parent_id: Option<String>,
+
+    pub manager: Option<Lookup>,
+    pub created_by: Option<Lookup>,
+    pub modified_by: Option<Lookup>,
+    pub created_time: Option<String>,
+    pub modified_time: Option<String>,
+}
+
+/// Response sent back from the [`org()`](../struct.Client.html#method.org) method.
+#[derive(Debug, Deserialize)]
+pub(crate) struct OrgResponse {
+    pub org: Vec<Org>,
+}
+
+/// Response sent back from the [`roles()`](../struct.Client.html#method.roles) method.
+#[derive(Debug, Deserialize)]
+pub(crate) struct RolesResponse {
+    pub roles: Vec<Role>,
+}
+
+/// Response sent back from the [`profiles()`](../struct.Client.html#method.profiles) method.
+#[derive(Debug, Deserialize)]
+pub(crate) struct ProfilesResponse {
+    pub profiles: Vec<Profile>,
+}
+
+/// Response sent back from the [`territories()`](../struct.Client.html#method.territories)
+/// method.
+#[derive(Debug, Default, Deserialize)]
+pub(crate) struct TerritoriesResponse {
+    pub territories: Vec<Territory>,
+}
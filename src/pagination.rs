@@ -0,0 +1,175 @@
+use crate::client::{self, Client};
+use crate::client_error::ClientError;
+use crate::params::ListParams;
+use crate::response::{ApiGetManyResponse, ApiGetManyResponseInfo};
+use futures::{stream, Future, Poll, Stream};
+use reqwest::r#async as async_http;
+use std::vec;
+
+/// Iterator over every record of a module, returned by the
+/// [`iter_all()`](struct.Client.html#method.iter_all) method.
+///
+/// Pages are fetched lazily, as the records of the previous page run out. If a page fails to
+/// load, the error is returned and the iterator stops.
+///
+/// When Zoho sends back a `next_page_token`, it is used to fetch the following page instead
+/// of the page number. This allows iterating past the 2,000 record limit of numbered pages.
+pub struct RecordIter<'a, T> {
+    client: &'a mut Client,
+    module: String,
+    params: ListParams,
+    records: vec::IntoIter<T>,
+    more_records: bool,
+}
+
+impl<'a, T: serde::de::DeserializeOwned> RecordIter<'a, T> {
+    pub(crate) fn new(client: &'a mut Client, module: &str, params: Option<ListParams>) -> RecordIter<'a, T> {
+        RecordIter {
+            client,
+            module: module.to_string(),
+            params: first_page(params),
+            records: Vec::new().into_iter(),
+            more_records: true,
+        }
+    }
+
+    /// Fetch the next page of records.
+    fn fetch_page(&mut self) -> Result<(), ClientError> {
+        let response = self.client.get_many::<T>(&self.module, Some(self.params.clone()))?;
+
+        next_page(&mut self.params, &response.info);
+
+        self.more_records = response.info.more_records;
+        self.records = response.data.into_iter();
+
+        Ok(())
+    }
+}
+
+impl<'a, T: serde::de::DeserializeOwned> Iterator for RecordIter<'a, T> {
+    type Item = Result<T, ClientError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(record) = self.records.next() {
+                return Some(Ok(record));
+            }
+
+            if !self.more_records {
+                return None;
+            }
+
+            if let Err(error) = self.fetch_page() {
+                self.more_records = false;
+                return Some(Err(error));
+            }
+        }
+    }
+}
+
+/// Stream of every record of a module, returned by the
+/// [`stream_all()`](struct.Client.html#method.stream_all) method.
+///
+/// This is the asynchronous version of [`RecordIter`](struct.RecordIter.html). Pages are
+/// fetched the same way, and the stream ends after the first error.
+#[must_use = "streams do nothing unless polled"]
+pub struct RecordStream<T> {
+    records: Box<dyn Stream<Item = T, Error = ClientError> + Send>,
+}
+
+impl<T: serde::de::DeserializeOwned + Send + 'static> RecordStream<T> {
+    pub(crate) fn new(http: async_http::Client, url: String, token: String, params: Option<ListParams>) -> RecordStream<T> {
+        let pages = stream::unfold(Some(first_page(params)), move |params| {
+            let mut params = params?;
+            let page = fetch_page::<T>(&http, &url, &token, &params).map(move |response| {
+                let next = if response.info.more_records {
+                    next_page(&mut params, &response.info);
+                    Some(params)
+                } else {
+                    None
+                };
+
+                (response.data, next)
+            });
+
+            Some(page)
+        });
+
+        RecordStream {
+            records: Box::new(pages.map(stream::iter_ok).flatten()),
+        }
+    }
+
+    /// A stream that only yields `error`.
+    pub(crate) fn failed(error: ClientError) -> RecordStream<T> {
+        RecordStream {
+            records: Box::new(stream::once(Err(error))),
+        }
+    }
+}
+
+impl<T> Stream for RecordStream<T> {
+    type Item = T;
+    type Error = ClientError;
+
+    fn poll(&mut self) -> Poll<Option<T>, ClientError> {
+        self.records.poll()
+    }
+}
+
+/// Fetch a page of records asynchronously. Like `get_many()`, an empty page is returned when
+/// Zoho sends back no content.
+fn fetch_page<T: serde::de::DeserializeOwned>(
+    http: &async_http::Client,
+    url: &str,
+    token: &str,
+    params: &ListParams
+) -> impl Future<Item = ApiGetManyResponse<T>, Error = ClientError> {
+    let mut request = http
+        .get(url)
+        .header("Authorization", format!("Zoho-oauthtoken {}", token))
+        .query(params);
+
+    if let Some(modified_since) = &params.modified_since {
+        request = request.header("If-Modified-Since", modified_since.as_str());
+    }
+
+    request
+        .send()
+        .and_then(|mut response| {
+            let status = response.status();
+
+            response.text().map(move |raw_response| (status, raw_response))
+        })
+        .map_err(ClientError::from)
+        .and_then(|(status, raw_response)| match status {
+            reqwest::StatusCode::NO_CONTENT | reqwest::StatusCode::NOT_MODIFIED => Ok(ApiGetManyResponse::default()),
+            _ => client::parse_response(&raw_response),
+        })
+}
+
+/// Start from the first page, unless a page or page token was given.
+fn first_page(params: Option<ListParams>) -> ListParams {
+    let mut params = params.unwrap_or_default();
+
+    if params.page_token.is_none() && params.page.is_none() {
+        params.page = Some(1);
+    }
+
+    params
+}
+
+/// Point `params` at the page after the one `info` was sent back with.
+fn next_page(params: &mut ListParams, info: &ApiGetManyResponseInfo) {
+    // page numbers stop working after 2,000 records, so prefer the token when given one
+    match &info.next_page_token {
+        Some(page_token) => {
+            params.page = None;
+            params.page_token = Some(page_token.clone());
+        },
+        None => {
+            params.page = Some(params.page.unwrap_or(info.page) + 1);
+            params.page_token = None;
+        },
+    }
+}
@@ -0,0 +1,298 @@
+use crate::client_error::ClientError;
+use crate::fields::field_names;
+use serde::{Serialize, Serializer};
+use std::fmt;
+
+/// Largest page size Zoho accepts.
+const MAX_PER_PAGE: usize = 200;
+
+/// Most fields Zoho accepts in a single `fields` parameter.
+const MAX_FIELDS: usize = 50;
+
+/// Direction to sort records in.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+impl fmt::Display for SortOrder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SortOrder::Asc => write!(f, "asc"),
+            SortOrder::Desc => write!(f, "desc"),
+        }
+    }
+}
+
+/// Whether to filter records on a flag, such as `converted` or `approved`.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FlagFilter {
+    /// Only records with the flag set.
+    True,
+
+    /// Only records without the flag set.
+    False,
+
+    /// Records with or without the flag set.
+    Both,
+}
+
+/// Which deleted records to fetch with the [`get_deleted()`](struct.Client.html#method.get_deleted)
+/// method.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DeletedType {
+    /// Records in the recycle bin, and records deleted permanently.
+    All,
+
+    /// Records in the recycle bin.
+    Recycle,
+
+    /// Records deleted permanently.
+    Permanent,
+}
+
+/// Parameters accepted by the [`get_deleted()`](struct.Client.html#method.get_deleted) method.
+///
+/// ### Example
+///
+/// ```
+/// use zoho_crm::{DeletedParams, DeletedType};
+///
+/// let params = DeletedParams {
+///     per_page: Some(50),
+///     ..DeletedParams::new(DeletedType::Recycle)
+/// };
+///
+/// assert!(params.validate().is_ok());
+/// ```
+#[derive(Clone, Debug, Serialize)]
+pub struct DeletedParams {
+    #[serde(rename = "type")]
+    pub deleted_type: DeletedType,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<usize>,
+
+    /// Number of records per page, up to 200.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<usize>,
+
+    /// Only return records deleted after this time, such as `2019-07-25T15:26:49+05:30`.
+    ///
+    /// This is sent as the `If-Modified-Since` header, rather than a URL parameter.
+    #[serde(skip)]
+    pub modified_since: Option<String>,
+}
+
+impl DeletedParams {
+    /// Create parameters that fetch the first page of the given type of deleted records.
+    pub fn new(deleted_type: DeletedType) -> DeletedParams {
+        DeletedParams {
+            deleted_type,
+            page: None,
+            per_page: None,
+            modified_since: None,
+        }
+    }
+
+    /// Check that the parameters are within the ranges Zoho accepts.
+    pub fn validate(&self) -> Result<(), ClientError> {
+        validate_page(self.page, self.per_page)
+    }
+}
+
+/// Parameters accepted by the [`get_many()`](struct.Client.html#method.get_many) method.
+///
+/// Any parameter left as `None` is not sent, and Zoho's default is used.
+///
+/// ### Example
+///
+/// ```
+/// use zoho_crm::ListParams;
+///
+/// let params = ListParams {
+///     cvid: Some(String::from("YOUR_VIEW_ID_HERE")),
+///     page: Some(2),
+///     per_page: Some(50),
+///     ..ListParams::default()
+/// };
+///
+/// assert!(params.validate().is_ok());
+/// ```
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ListParams {
+    /// API names of the fields to return. All fields are returned when this is empty.
+    #[serde(serialize_with = "serialize_fields", skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_by: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<SortOrder>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub converted: Option<FlagFilter>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub approved: Option<FlagFilter>,
+
+    /// ID of the custom view to list records from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cvid: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub territory_id: Option<String>,
+
+    /// Include records from child territories. Requires `territory_id`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_child: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<usize>,
+
+    /// Number of records per page, up to 200.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<usize>,
+
+    /// Token from `next_page_token`, used to fetch pages past the first 2,000 records.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_token: Option<String>,
+
+    /// Only return records modified after this time, such as `2019-07-25T15:26:49+05:30`.
+    ///
+    /// This is sent as the `If-Modified-Since` header, rather than a URL parameter.
+    #[serde(skip)]
+    pub modified_since: Option<String>,
+}
+
+impl ListParams {
+    /// Create parameters that only fetch the fields `T` deserializes from.
+    ///
+    /// See [`field_names()`](fn.field_names.html) for how the fields are found.
+    pub fn for_type<T: serde::de::DeserializeOwned>() -> ListParams {
+        ListParams {
+            fields: field_names::<T>().iter().map(|field| field.to_string()).collect(),
+            ..ListParams::default()
+        }
+    }
+
+    /// Check that the parameters are within the ranges Zoho accepts.
+    pub fn validate(&self) -> Result<(), ClientError> {
+        validate_page(self.page, self.per_page)?;
+
+        validate_fields(self.fields.len())?;
+
+        if self.include_child.is_some() && self.territory_id.is_none() {
+            return Err(ClientError::InvalidParams(String::from("include_child requires territory_id")));
+        }
+
+        if self.page.is_some() && self.page_token.is_some() {
+            return Err(ClientError::InvalidParams(String::from("page and page_token cannot be used together")));
+        }
+
+        Ok(())
+    }
+}
+
+/// Check that a page number and page size are within the ranges Zoho accepts.
+pub(crate) fn validate_page(page: Option<usize>, per_page: Option<usize>) -> Result<(), ClientError> {
+    if page == Some(0) {
+        return Err(ClientError::InvalidParams(String::from("page must be at least 1")));
+    }
+
+    if let Some(per_page) = per_page {
+        if per_page == 0 || per_page > MAX_PER_PAGE {
+            return Err(ClientError::InvalidParams(format!("per_page must be between 1 and {}", MAX_PER_PAGE)));
+        }
+    }
+
+    Ok(())
+}
+
+/// Check that no more fields are asked for than Zoho accepts.
+pub(crate) fn validate_fields(count: usize) -> Result<(), ClientError> {
+    if count > MAX_FIELDS {
+        return Err(ClientError::InvalidParams(format!("fields must not contain more than {} fields", MAX_FIELDS)));
+    }
+
+    Ok(())
+}
+
+/// Zoho expects the list of fields as a single comma-separated value.
+fn serialize_fields<S: Serializer>(fields: &[String], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&fields.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_empty() {
+        let params = ListParams::default();
+
+        assert_eq!(serde_urlencoded::to_string(&params).unwrap(), "");
+    }
+
+    #[test]
+    fn serialize_all() {
+        let params = ListParams {
+            fields: vec![String::from("Last_Name"), String::from("Email")],
+            sort_by: Some(String::from("Created_Time")),
+            sort_order: Some(SortOrder::Desc),
+            converted: Some(FlagFilter::Both),
+            approved: Some(FlagFilter::True),
+            cvid: Some(String::from("100")),
+            territory_id: Some(String::from("200")),
+            include_child: Some(true),
+            page: Some(2),
+            per_page: Some(200),
+            page_token: None,
+            modified_since: Some(String::from("2019-07-25T15:26:49+05:30")),
+        };
+
+        assert_eq!(
+            serde_urlencoded::to_string(&params).unwrap(),
+            "fields=Last_Name%2CEmail&sort_by=Created_Time&sort_order=desc&converted=both&approved=true\
+             &cvid=100&territory_id=200&include_child=true&page=2&per_page=200"
+        );
+        assert!(params.validate().is_ok());
+    }
+
+    #[test]
+    fn invalid_per_page() {
+        for per_page in &[0, 201] {
+            let params = ListParams {
+                per_page: Some(*per_page),
+                ..ListParams::default()
+            };
+
+            assert!(params.validate().is_err());
+        }
+    }
+
+    #[test]
+    fn invalid_page() {
+        let params = ListParams {
+            page: Some(0),
+            ..ListParams::default()
+        };
+
+        assert!(params.validate().is_err());
+    }
+
+    #[test]
+    fn include_child_without_territory() {
+        let params = ListParams {
+            include_child: Some(true),
+            ..ListParams::default()
+        };
+
+        assert!(params.validate().is_err());
+    }
+}
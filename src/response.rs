@@ -1,6 +1,8 @@
 //! Various response objects returned from Zoho.
 
+use crate::metadata::DataType;
 use serde::Deserialize;
+use std::fmt;
 
 /// Wrapper around a successful response using the `get()` method.
 #[derive(Debug, Deserialize)]
@@ -18,13 +20,66 @@ pub struct ApiGetManyResponse<T> {
     pub info: ApiGetManyResponseInfo,
 }
 
+/// An empty page of records. This is returned when Zoho has no records to send back.
+impl<T> Default for ApiGetManyResponse<T> {
+    fn default() -> Self {
+        ApiGetManyResponse {
+            data: Vec::new(),
+            info: ApiGetManyResponseInfo::default(),
+        }
+    }
+}
+
 /// Meta data sent back with the `get_many()` method.
-#[derive(Debug, Deserialize)]
+///
+/// COQL queries do not send back `page` or `per_page`, so they will be `0` in that case.
+///
+/// Zoho will not serve more than 2,000 records through `page` and `per_page`. Past that point,
+/// the next page must be requested with the `page_token` parameter set to `next_page_token`.
+#[derive(Debug, Default, Deserialize)]
 pub struct ApiGetManyResponseInfo {
     pub count: usize,
     pub more_records: bool,
+
+    #[serde(default)]
     pub page: usize,
+
+    #[serde(default)]
     pub per_page: usize,
+
+    pub next_page_token: Option<String>,
+    pub previous_page_token: Option<String>,
+    pub page_token_expiry: Option<String>,
+}
+
+/// Response sent back from the `count()` method.
+#[derive(Debug, Default, Deserialize)]
+pub(crate) struct CountResponse {
+    pub count: usize,
+}
+
+/// Reference to another record or user, such as the owner of a record.
+#[derive(Debug, Deserialize)]
+pub struct Lookup {
+    pub id: String,
+    pub name: Option<String>,
+}
+
+/// Record returned by the `get_deleted()` method.
+///
+/// Zoho does not keep who deleted a record, or its display name, once it has been deleted
+/// permanently.
+#[derive(Debug, Deserialize)]
+pub struct DeletedRecord {
+    pub id: String,
+    pub deleted_by: Option<Lookup>,
+    pub deleted_time: Option<String>,
+    pub display_name: Option<String>,
+    pub created_by: Option<Lookup>,
+
+    /// Either `recycle` or `permanent`.
+    #[serde(rename = "type")]
+    pub deleted_type: String,
 }
 
 /// This is one possible error response that Zoho might send back when requesting a token. If
@@ -64,6 +119,13 @@ pub struct ResponseDataItemDetailsError {
     pub index: Option<String>,
 }
 
+impl ResponseDataItemDetailsError {
+    /// Get the type of value Zoho expected for the field named by `api_name`.
+    pub fn expected_type(&self) -> Option<DataType> {
+        self.expected_data_type.as_deref().map(DataType::from)
+    }
+}
+
 /// Response details object returned when a record was succesfully insert or updated.
 ///
 /// There are some other fields, shown [here](https://www.zoho.com/crm/developer/docs/api/insert-records.html),
@@ -79,6 +141,63 @@ pub struct ResponseDataItemDetailsSuccess {
     pub id: String,
 }
 
+/// Response sent back from the `update_related()` and `delist_related()` methods.
+#[derive(Debug, Deserialize)]
+pub struct RelatedRecordsResponse {
+    pub data: Vec<RelatedRecordsResponseItem>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RelatedRecordsResponseItem {
+    pub code: String,
+    pub details: RelatedRecordDetails,
+    pub message: String,
+    pub status: String,
+}
+
+/// Details of a single related record. Zoho only sends back the `id` of the related record,
+/// and the `api_name` of the field at fault when it could not be updated.
+#[derive(Debug, Deserialize)]
+pub struct RelatedRecordDetails {
+    pub id: Option<String>,
+    pub api_name: Option<String>,
+}
+
+/// Response sent back from actions whose details only hold the ID of the record acted on,
+/// such as deleting notes.
+///
+/// Like [`ApiSuccessResponse`](struct.ApiSuccessResponse.html), errors for individual records
+/// are returned alongside the record in a successful response.
+#[derive(Debug, Deserialize)]
+pub struct ApiActionResponse {
+    pub data: Vec<ApiActionResponseItem>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ApiActionResponseItem {
+    pub code: String,
+    pub details: ApiActionDetails,
+    pub message: String,
+    pub status: String,
+}
+
+/// On success, `id` is set to the ID of the record. On error, `api_name` may point to the
+/// offending field.
+#[derive(Debug, Deserialize)]
+pub struct ApiActionDetails {
+    pub id: Option<String>,
+    pub api_name: Option<String>,
+}
+
+/// Response sent back from actions that only report whether they succeeded, such as uploading
+/// a record photo.
+#[derive(Debug, Deserialize)]
+pub struct ApiStatusResponse {
+    pub code: String,
+    pub message: String,
+    pub status: String,
+}
+
 /// This is one possible error response that Zoho might send back from an API request. It is
 /// different than the response format given back when requesting a token. `code` will be an
 /// identifier for the type of error, while the `message` field *might* have more information.
@@ -99,8 +218,14 @@ pub struct ApiErrorResponse {
 }
 
 impl ApiErrorResponse {
-    #[allow(dead_code)]
+    #[allow(dead_code, clippy::inherent_to_string_shadow_display)]
     pub fn to_string(&self) -> String {
         format!("[{}] {}", self.code, self.message)
     }
 }
+
+impl fmt::Display for ApiErrorResponse {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}] {}", self.code, self.message)
+    }
+}
@@ -0,0 +1,102 @@
+//! Types used with the [`search()`](../struct.Client.html#method.search) and
+//! [`count()`](../struct.Client.html#method.count) methods.
+
+use crate::criteria::Criteria;
+use serde::Serialize;
+
+/// The kind of search to run with the [`search()`](../struct.Client.html#method.search) method.
+///
+/// Zoho only accepts one of these per search.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchQuery {
+    /// Search by a criteria expression. See [`Criteria`](../criteria/enum.Criteria.html) for building one.
+    Criteria(Criteria),
+
+    /// Search all email fields of the module.
+    Email(String),
+
+    /// Search all phone fields of the module.
+    Phone(String),
+
+    /// Search for a word across all fields of the module.
+    Word(String),
+}
+
+/// Parameters sent along with the [`search()`](../struct.Client.html#method.search) method.
+#[derive(Clone, Debug, Serialize)]
+pub struct SearchParams {
+    #[serde(flatten)]
+    pub query: SearchQuery,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<usize>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<usize>,
+}
+
+impl SearchParams {
+    /// Create search parameters for the first page of results.
+    pub fn new(query: SearchQuery) -> SearchParams {
+        SearchParams {
+            query,
+            page: None,
+            per_page: None,
+        }
+    }
+}
+
+/// Filter for the records counted by the [`count()`](../struct.Client.html#method.count) method.
+#[derive(Clone, Debug)]
+pub enum CountFilter {
+    /// Count the records a search would return.
+    Search(SearchQuery),
+
+    /// Count the records in the custom view with the given ID.
+    Cvid(String),
+}
+
+impl From<SearchQuery> for CountFilter {
+    fn from(query: SearchQuery) -> Self {
+        CountFilter::Search(query)
+    }
+}
+
+impl From<Criteria> for CountFilter {
+    fn from(criteria: Criteria) -> Self {
+        CountFilter::Search(SearchQuery::Criteria(criteria))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_query() {
+        let params = SearchParams::new(SearchQuery::Email(String::from("burns@example.com")));
+
+        assert_eq!(serde_urlencoded::to_string(&params).unwrap(), "email=burns%40example.com");
+    }
+
+    #[test]
+    fn serialize_criteria() {
+        let criteria = Criteria::equals("Last_Name", "Burns").and(Criteria::starts_with("First_Name", "B"));
+        let params = SearchParams::new(SearchQuery::Criteria(criteria));
+
+        assert_eq!(
+            serde_urlencoded::to_string(&params).unwrap(),
+            "criteria=%28%28Last_Name%3Aequals%3ABurns%29and%28First_Name%3Astarts_with%3AB%29%29"
+        );
+    }
+
+    #[test]
+    fn serialize_pagination() {
+        let mut params = SearchParams::new(SearchQuery::Word(String::from("Burns")));
+        params.page = Some(2);
+        params.per_page = Some(50);
+
+        assert_eq!(serde_urlencoded::to_string(&params).unwrap(), "word=Burns&page=2&per_page=50");
+    }
+}
@@ -0,0 +1,81 @@
+//! Types used with the tag methods of the [`Client`](../struct.Client.html).
+
+use crate::response::Lookup;
+use serde::Deserialize;
+
+/// A tag that can be added to records of a module.
+#[derive(Debug, Deserialize)]
+pub struct Tag {
+    pub id: String,
+    pub name: String,
+    pub created_by: Option<Lookup>,
+    pub modified_by: Option<Lookup>,
+    pub created_time: Option<String>,
+    pub modified_time: Option<String>,
+}
+
+/// Response sent back from the `tags()` method.
+#[derive(Debug, Default, Deserialize)]
+pub struct TagsResponse {
+    pub tags: Vec<Tag>,
+    pub info: TagsResponseInfo,
+}
+
+/// Meta data sent back with the `tags()` method.
+#[derive(Debug, Default, Deserialize)]
+pub struct TagsResponseInfo {
+    pub count: usize,
+
+    /// The most tags the module can have.
+    #[serde(default)]
+    pub allowed_count: usize,
+}
+
+/// Response sent back when creating, updating, merging or deleting tags.
+///
+/// Like [`ApiSuccessResponse`](../response/struct.ApiSuccessResponse.html), errors for
+/// individual tags are returned alongside the tag in a successful response.
+#[derive(Debug, Deserialize)]
+pub struct TagActionResponse {
+    pub tags: Vec<TagActionResponseItem>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TagActionResponseItem {
+    pub code: String,
+    pub details: TagActionDetails,
+    pub message: String,
+    pub status: String,
+}
+
+/// On success, `id` is set to the ID of the tag. On error, `api_name` may point to the
+/// offending field.
+#[derive(Debug, Deserialize)]
+pub struct TagActionDetails {
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub api_name: Option<String>,
+}
+
+/// Response sent back when adding tags to, or removing tags from records.
+#[derive(Debug, Deserialize)]
+pub struct RecordTagsResponse {
+    pub data: Vec<RecordTagsResponseItem>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RecordTagsResponseItem {
+    pub code: String,
+    pub details: RecordTagsDetails,
+    pub message: String,
+    pub status: String,
+}
+
+/// The record that was changed, and the names of the tags it has now.
+#[derive(Debug, Deserialize)]
+pub struct RecordTagsDetails {
+    pub id: Option<String>,
+
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
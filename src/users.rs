@@ -0,0 +1,170 @@
+//! Types used with the user methods of the [`Client`](../struct.Client.html).
+
+use crate::client_error::ClientError;
+use crate::params::validate_page;
+use crate::response::{ApiGetManyResponse, ApiGetManyResponseInfo, Lookup};
+use serde::{Deserialize, Serialize};
+
+/// Which users to fetch with the [`users()`](../struct.Client.html#method.users) method.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub enum UserType {
+    AllUsers,
+    ActiveUsers,
+    DeactiveUsers,
+    ConfirmedUsers,
+    NotConfirmedUsers,
+    DeletedUsers,
+    ActiveConfirmedUsers,
+    AdminUsers,
+    ActiveConfirmedAdmins,
+
+    /// The user the access token belongs to.
+    CurrentUser,
+}
+
+/// Parameters accepted by the [`users()`](../struct.Client.html#method.users) method.
+///
+/// ### Example
+///
+/// ```
+/// use zoho_crm::users::{UserType, UsersParams};
+///
+/// let params = UsersParams {
+///     page: Some(2),
+///     ..UsersParams::new(UserType::ActiveUsers)
+/// };
+///
+/// assert!(params.validate().is_ok());
+/// ```
+#[derive(Clone, Debug, Serialize)]
+pub struct UsersParams {
+    #[serde(rename = "type")]
+    pub user_type: UserType,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<usize>,
+
+    /// Number of users per page, up to 200.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<usize>,
+}
+
+impl UsersParams {
+    /// Create parameters that fetch the first page of the given type of users.
+    pub fn new(user_type: UserType) -> UsersParams {
+        UsersParams {
+            user_type,
+            page: None,
+            per_page: None,
+        }
+    }
+
+    /// Check that the parameters are within the ranges Zoho accepts.
+    pub fn validate(&self) -> Result<(), ClientError> {
+        validate_page(self.page, self.per_page)
+    }
+}
+
+/// A user of your Zoho organization.
+#[derive(Debug, Deserialize)]
+pub struct User {
+    pub id: String,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub full_name: Option<String>,
+    pub email: Option<String>,
+
+    pub role: Option<Lookup>,
+    pub profile: Option<Lookup>,
+
+    /// Either `active`, `disabled` or `deleted`.
+    pub status: Option<String>,
+
+    /// Such as `Asia/Kolkata`.
+    pub time_zone: Option<String>,
+
+    /// Such as `en_US`.
+    pub locale: Option<String>,
+
+    /// Whether the user has accepted the invitation to the organization.
+    #[serde(default)]
+    pub confirm: bool,
+
+    pub created_by: Option<Lookup>,
+    pub created_time: Option<String>,
+
+    #[serde(rename = "Modified_By")]
+    pub modified_by: Option<Lookup>,
+
+    #[serde(rename = "Modified_Time")]
+    pub modified_time: Option<String>,
+}
+
+/// Data sent when creating or updating a user. Any field left as `None` is not sent.
+///
+/// `last_name`, `email`, `role` and `profile` are required when creating users.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct UserData {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+
+    /// ID of the role to give the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+
+    /// ID of the profile to give the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_zone: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+}
+
+/// Response sent back when fetching users.
+#[derive(Debug, Default, Deserialize)]
+pub(crate) struct UsersResponse {
+    pub users: Vec<User>,
+
+    #[serde(default)]
+    pub info: ApiGetManyResponseInfo,
+}
+
+impl From<UsersResponse> for ApiGetManyResponse<User> {
+    fn from(response: UsersResponse) -> Self {
+        ApiGetManyResponse {
+            data: response.users,
+            info: response.info,
+        }
+    }
+}
+
+/// Response sent back when creating, updating or deleting users.
+#[derive(Debug, Deserialize)]
+pub struct UserActionResponse {
+    pub users: Vec<UserActionResponseItem>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UserActionResponseItem {
+    pub code: String,
+    pub details: UserActionDetails,
+    pub message: String,
+    pub status: String,
+}
+
+/// On success, `id` is set to the ID of the user. On error, `api_name` may point to the
+/// offending field.
+#[derive(Debug, Deserialize)]
+pub struct UserActionDetails {
+    pub id: Option<String>,
+    pub api_name: Option<String>,
+}